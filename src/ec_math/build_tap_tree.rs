@@ -0,0 +1,31 @@
+use crate::types::{TapLeaf, TapTree, Errors};
+
+/// Builds a balanced script tree of the leaves, keeping their order from left to right.
+/// Every leaf ends up at depth floor(log2(n)) or one deeper. Returns EmptyTapTree if there are no leaves.
+pub fn build_tap_tree(leaves: &[TapLeaf]) -> Result<TapTree, Errors> {
+    match leaves.len() {
+        0 => Err(Errors::EmptyTapTree),
+        1 => Ok(TapTree::Leaf(leaves[0].clone())),
+        n => Ok(TapTree::branch(build_tap_tree(&leaves[..n / 2])?, build_tap_tree(&leaves[n / 2..])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_tap_tree() -> Result<(), Errors> {
+        let leaves: Vec<TapLeaf> = (0x51..=0x55).map(|op| TapLeaf::tapscript(vec![op])).collect();
+        let leaf = |i: usize| TapTree::Leaf(leaves[i].clone());
+        assert_eq!(build_tap_tree(&leaves[..1])?, leaf(0));
+        // 5 leaves: [[0, 1], [2, [3, 4]]]
+        let tree = build_tap_tree(&leaves)?;
+        let expected = TapTree::branch(TapTree::branch(leaf(0), leaf(1)), TapTree::branch(leaf(2), TapTree::branch(leaf(3), leaf(4))));
+        assert_eq!(tree, expected);
+        assert_eq!(tree.leaves(), leaves.iter().collect::<Vec<&TapLeaf>>());
+        assert_eq!(tree.depth(), 3);
+        assert_eq!(build_tap_tree(&[]), Err(Errors::EmptyTapTree));
+        Ok(())
+    }
+}
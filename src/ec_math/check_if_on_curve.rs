@@ -1,5 +1,4 @@
-use crate::types::{U512, Points, EllipticCurve, ECpoint, Errors};
-use crate::group_math::a_inverse_mod;
+use crate::types::{FieldElement, Points, EllipticCurve, ECpoint, Errors};
 
 /// Returns Some(ECpoint) if the point lies on the curve None otherwise
 pub fn check_if_on_curve<P: Into<Points>>(p: P, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
@@ -8,19 +7,12 @@ pub fn check_if_on_curve<P: Into<Points>>(p: P, curve: &EllipticCurve) -> Result
         Points::Infinity => Ok(ECpoint::Infinity),
         Points::FinitePoint(point) => {
             //y^2 = x^3 + ax + b
-            let x: U512 = point.x.into();
-            let y: U512 = point.y.into();
-            let p: U512 = curve.p.into();
-            let a: U512 = curve.a.into();
-            let b: U512 = curve.b.into();
-            let y_2 = (y * y) % p;
-            let x_3 = (((x * x) % p ) * x) % p;
-            let minus_x3 = U512::from(a_inverse_mod(x_3, p)?);
-            let ax = (a * x) % p;
-            let minus_ax = U512::from(a_inverse_mod(ax, p)?);
-            let minus_b = U512::from(a_inverse_mod(b, p)?);
-            let check_equation = (y_2 + minus_x3 + minus_ax + minus_b) % p;
-            match  check_equation == U512::zero() {
+            let x = FieldElement::new(point.x, curve.p);
+            let y = FieldElement::new(point.y, curve.p);
+            let a = FieldElement::from_i64(curve.a, curve.p);
+            let b = FieldElement::from_i64(curve.b, curve.p);
+            let check_equation = y.square() - x.square() * x - a * x - b;
+            match check_equation.is_zero() {
                 true => Ok(ECpoint::OnCurve(point)),
                 false => Err(Errors::PointNotOnCurve(point))
             }
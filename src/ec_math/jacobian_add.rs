@@ -0,0 +1,65 @@
+use crate::types::{JacobianPoint, EllipticCurve};
+use crate::ec_math::jacobian_double;
+
+/// Returns p + q, computed in Jacobian coordinates without an inversion.
+pub fn jacobian_add(p: &JacobianPoint, q: &JacobianPoint, curve: &EllipticCurve) -> JacobianPoint {
+    if p.is_infinity() {
+        return *q;
+    }
+    if q.is_infinity() {
+        return *p;
+    }
+    let z1z1 = p.z.square();
+    let z2z2 = q.z.square();
+    let u1 = p.x * z2z2;
+    let u2 = q.x * z1z1;
+    let s1 = p.y * q.z * z2z2;
+    let s2 = q.y * p.z * z1z1;
+    let h = u2 - u1;
+    let r = s2 - s1;
+    if h.is_zero() {
+        return match r.is_zero() {
+            // p == q
+            true => jacobian_double(p, curve),
+            // p == -q
+            false => JacobianPoint::infinity(curve)
+        };
+    }
+    let hh = h.square();
+    let hhh = h * hh;
+    let v = u1 * hh;
+    let x3 = r.square() - hhh - v - v;
+    let y3 = r * (v - x3) - s1 * hhh;
+    let z3 = p.z * q.z * h;
+    JacobianPoint { x: x3, y: y3, z: z3 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Point, Points, ECpoint, Errors};
+    use crate::ec_math::{point_add, point_neg};
+
+    #[test]
+    fn test_jacobian_add() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let g = ECpoint::OnCurve(Point::from(secp256k1.g));
+        let g2 = point_add(&g.into(), &g.into(), &secp256k1)?;
+        let g3 = point_add(&g.into(), &g2.into(), &secp256k1)?;
+        let infinity = JacobianPoint::infinity(&secp256k1);
+        let jg = JacobianPoint::from_affine(&g, &secp256k1);
+        let jg2 = jacobian_double(&jg, &secp256k1);
+        //G + 2G = 3G, with 2G having Z != 1
+        assert_eq!(jacobian_add(&jg, &jg2, &secp256k1).to_affine()?, g3);
+        assert_eq!(jacobian_add(&jg2, &jg, &secp256k1).to_affine()?, g3);
+        //G + G = 2G
+        assert_eq!(jacobian_add(&jg, &jg, &secp256k1).to_affine()?, g2);
+        //0 + G = G + 0 = G
+        assert_eq!(jacobian_add(&infinity, &jg, &secp256k1).to_affine()?, g);
+        assert_eq!(jacobian_add(&jg, &infinity, &secp256k1).to_affine()?, g);
+        //G + (-G) = 0
+        let minus_g = JacobianPoint::from_affine(&point_neg(&Points::from(g), &secp256k1)?, &secp256k1);
+        assert!(jacobian_add(&jg, &minus_g, &secp256k1).is_infinity());
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::types::{FieldElement, JacobianPoint, ECpoint, EllipticCurve};
+use crate::ec_math::jacobian_double;
+
+/// Returns p + q for an affine q (Z = 1), which saves several multiplications over jacobian_add.
+pub fn jacobian_add_mixed(p: &JacobianPoint, q: &ECpoint, curve: &EllipticCurve) -> JacobianPoint {
+    let q = match q {
+        ECpoint::Infinity => return *p,
+        ECpoint::OnCurve(q) => q
+    };
+    if p.is_infinity() {
+        return JacobianPoint::from_affine(&ECpoint::OnCurve(*q), curve);
+    }
+    let x2 = FieldElement::new(q.x, curve.p);
+    let y2 = FieldElement::new(q.y, curve.p);
+    let z1z1 = p.z.square();
+    let u2 = x2 * z1z1;
+    let s2 = y2 * p.z * z1z1;
+    let h = u2 - p.x;
+    let r = s2 - p.y;
+    if h.is_zero() {
+        return match r.is_zero() {
+            // p == q
+            true => jacobian_double(p, curve),
+            // p == -q
+            false => JacobianPoint::infinity(curve)
+        };
+    }
+    let hh = h.square();
+    let hhh = h * hh;
+    let v = p.x * hh;
+    let x3 = r.square() - hhh - v - v;
+    let y3 = r * (v - x3) - p.y * hhh;
+    let z3 = p.z * h;
+    JacobianPoint { x: x3, y: y3, z: z3 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Point, Points, Errors};
+    use crate::ec_math::{point_add, point_neg};
+
+    #[test]
+    fn test_jacobian_add_mixed() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let g = ECpoint::OnCurve(Point::from(secp256k1.g));
+        let g2 = point_add(&g.into(), &g.into(), &secp256k1)?;
+        let g3 = point_add(&g.into(), &g2.into(), &secp256k1)?;
+        let infinity = JacobianPoint::infinity(&secp256k1);
+        let jg2 = jacobian_double(&JacobianPoint::from_affine(&g, &secp256k1), &secp256k1);
+        //2G + G = 3G, with 2G having Z != 1
+        assert_eq!(jacobian_add_mixed(&jg2, &g, &secp256k1).to_affine()?, g3);
+        //2G + 2G = 4G
+        let g4 = point_add(&g2.into(), &g2.into(), &secp256k1)?;
+        assert_eq!(jacobian_add_mixed(&jg2, &g2, &secp256k1).to_affine()?, g4);
+        //0 + G = G, 2G + 0 = 2G
+        assert_eq!(jacobian_add_mixed(&infinity, &g, &secp256k1).to_affine()?, g);
+        assert_eq!(jacobian_add_mixed(&jg2, &ECpoint::Infinity, &secp256k1).to_affine()?, g2);
+        //2G + (-2G) = 0
+        let minus_g2 = point_neg(&Points::from(g2), &secp256k1)?;
+        assert!(jacobian_add_mixed(&jg2, &minus_g2, &secp256k1).is_infinity());
+        Ok(())
+    }
+}
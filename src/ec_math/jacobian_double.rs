@@ -0,0 +1,47 @@
+use crate::types::{FieldElement, JacobianPoint, EllipticCurve};
+
+/// Returns 2 * p, computed in Jacobian coordinates without an inversion.
+pub fn jacobian_double(p: &JacobianPoint, curve: &EllipticCurve) -> JacobianPoint {
+    if p.is_infinity() || p.y.is_zero() {
+        return JacobianPoint::infinity(curve);
+    }
+    let JacobianPoint { x, y, z } = *p;
+    let xx = x.square();
+    let yy = y.square();
+    // s = 4 * x * y^2
+    let s = x * yy;
+    let s = s + s;
+    let s = s + s;
+    // m = 3 * x^2 + a * z^4
+    let mut m = xx + xx + xx;
+    if curve.a != 0 {
+        m = m + FieldElement::from_i64(curve.a, curve.p) * z.square().square();
+    }
+    let x3 = m.square() - s - s;
+    // y3 = m * (s - x3) - 8 * y^4
+    let yyyy = yy.square();
+    let yyyy_2 = yyyy + yyyy;
+    let yyyy_4 = yyyy_2 + yyyy_2;
+    let y3 = m * (s - x3) - (yyyy_4 + yyyy_4);
+    let z3 = (y + y) * z;
+    JacobianPoint { x: x3, y: y3, z: z3 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, Point, ECpoint, Errors};
+
+    #[test]
+    fn test_jacobian_double() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let g = JacobianPoint::from_affine(&ECpoint::OnCurve(Point::from(secp256k1.g)), &secp256k1);
+        let result_x = U256::from_dec_str("89565891926547004231252920425935692360644145829622209833684329913297188986597").unwrap();
+        let result_y = U256::from_dec_str("12158399299693830322967808612713398636155367887041628176798871954788371653930").unwrap();
+        //2 * G = result
+        assert_eq!(jacobian_double(&g, &secp256k1).to_affine()?, ECpoint::OnCurve(Point::new(result_x, result_y)));
+        //2 * 0 = 0
+        assert!(jacobian_double(&JacobianPoint::infinity(&secp256k1), &secp256k1).is_infinity());
+        Ok(())
+    }
+}
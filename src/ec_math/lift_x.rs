@@ -0,0 +1,65 @@
+use crate::types::{U256, FieldElement, Point, EllipticCurve, ECpoint, Errors};
+use crate::group_math::sqrt_mod;
+
+/// Returns the point of the curve with the given x coordinate, of the two possible y values
+/// the odd one is picked if `parity` is true and the even one otherwise.
+/// Returns XNotOnCurve if x is not below p or there is no such point.
+pub fn lift_x(x: U256, parity: bool, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+    if x >= curve.p {
+        return Err(Errors::XNotOnCurve(x));
+    }
+    //y^2 = x^3 + ax + b
+    let x_elem = FieldElement::new(x, curve.p);
+    let a = FieldElement::from_i64(curve.a, curve.p);
+    let b = FieldElement::from_i64(curve.b, curve.p);
+    let y_2 = x_elem.square() * x_elem + a * x_elem + b;
+    let y = match sqrt_mod(y_2.value(), curve.p) {
+        Ok(y) => FieldElement::new(y, curve.p),
+        Err(Errors::NoSquareRoot(_)) => return Err(Errors::XNotOnCurve(x)),
+        Err(e) => return Err(e)
+    };
+    let y = match y.value().bit(0) == parity {
+        true => y,
+        false => -y
+    };
+    // y = 0 has no partner of the other parity
+    if y.value().bit(0) != parity {
+        return Err(Errors::XNotOnCurve(x));
+    }
+    Ok(ECpoint::OnCurve(Point::new(x, y.value())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ec_math::{check_if_on_curve, point_neg};
+
+    #[test]
+    fn test_lift_x() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let g = ECpoint::OnCurve(Point::from(secp256k1.g));
+        // y of the base point is even
+        assert_eq!(lift_x(secp256k1.g.0, false, &secp256k1)?, g);
+        assert_eq!(lift_x(secp256k1.g.0, true, &secp256k1)?, point_neg(&g.into(), &secp256k1)?);
+        // there is no point with x = 0 on secp256k1 (7 is not a square)
+        assert_eq!(lift_x(U256::zero(), false, &secp256k1), Err(Errors::XNotOnCurve(U256::zero())));
+        assert_eq!(lift_x(secp256k1.p, false, &secp256k1), Err(Errors::XNotOnCurve(secp256k1.p)));
+        // y^2 = x^3 + 2x + 3 mod 97 has 100 points, 99 of them finite
+        let mut tutorial = EllipticCurve::new("tutorial");
+        tutorial.set_p("97").set_a(2).set_b(3).set_g(("3", "6")).set_n("5").set_h("20");
+        assert_eq!(lift_x(U256::from(3), false, &tutorial)?, ECpoint::OnCurve(Point::new(U256::from(3), U256::from(6))));
+        assert_eq!(lift_x(U256::from(3), true, &tutorial)?, ECpoint::OnCurve(Point::new(U256::from(3), U256::from(91))));
+        let mut points = 0;
+        for x in 0..97u64 {
+            for parity in [false, true].iter() {
+                if let Ok(point) = lift_x(U256::from(x), *parity, &tutorial) {
+                    assert_eq!(check_if_on_curve(point, &tutorial)?, point);
+                    assert_eq!(point.to_finite_point().y.bit(0), *parity);
+                    points += 1;
+                }
+            }
+        }
+        assert_eq!(points, 99);
+        Ok(())
+    }
+}
@@ -1,8 +1,40 @@
 pub mod check_if_on_curve;
 pub use self::check_if_on_curve::check_if_on_curve;
+pub mod lift_x;
+pub use self::lift_x::lift_x;
 pub mod point_neg;
 pub use self::point_neg::point_neg;
 pub mod point_add;
 pub use self::point_add::point_add;
+pub mod jacobian_double;
+pub use self::jacobian_double::jacobian_double;
+pub mod jacobian_add;
+pub use self::jacobian_add::jacobian_add;
+pub mod jacobian_add_mixed;
+pub use self::jacobian_add_mixed::jacobian_add_mixed;
 pub mod scalar_mult;
 pub use self::scalar_mult::scalar_mult;
+pub mod scalar_mult_ladder;
+pub use self::scalar_mult_ladder::scalar_mult_ladder;
+pub mod scalar_mult_wnaf;
+pub use self::scalar_mult_wnaf::scalar_mult_wnaf;
+pub mod scalar_mult_base;
+pub use self::scalar_mult_base::scalar_mult_base;
+pub mod multi_scalar_mult;
+pub use self::multi_scalar_mult::multi_scalar_mult;
+pub mod tap_tweak;
+pub use self::tap_tweak::tap_tweak;
+pub mod taproot_tweak_public_key;
+pub use self::taproot_tweak_public_key::taproot_tweak_public_key;
+pub mod tap_leaf_hash;
+pub use self::tap_leaf_hash::tap_leaf_hash;
+pub mod tap_branch_hash;
+pub use self::tap_branch_hash::tap_branch_hash;
+pub mod tap_merkle_root;
+pub use self::tap_merkle_root::tap_merkle_root;
+pub mod build_tap_tree;
+pub use self::build_tap_tree::build_tap_tree;
+pub mod tap_control_blocks;
+pub use self::tap_control_blocks::tap_control_blocks;
+pub mod verify_control_block;
+pub use self::verify_control_block::verify_control_block;
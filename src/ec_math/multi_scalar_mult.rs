@@ -0,0 +1,118 @@
+use crate::types::{U256, Points, EllipticCurve, ECpoint, Errors, JacobianPoint};
+use crate::ec_math::{check_if_on_curve, jacobian_add, jacobian_add_mixed, jacobian_double};
+use crate::ec_math::scalar_mult_wnaf::{wnaf, odd_multiples};
+
+/// From this many terms on the bucket method (Pippenger) is used instead of interleaving (Strauss).
+const PIPPENGER_THRESHOLD: usize = 64;
+/// Window width of the wNAF digits used by Strauss' method.
+const STRAUSS_WINDOW: usize = 5;
+
+///Returns k1 * P1 + k2 * P2 + ... + kn * Pn.
+///For a few terms the doublings are shared between all of them (Strauss, or Shamir's trick for two),
+///for many terms Pippenger's bucket method is used. Runs in variable time, so the scalars must be public.
+pub fn multi_scalar_mult(terms: &[(U256, Points)], curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+    let result = multi_scalar_mult_jacobian(terms, curve)?;
+    let result = check_if_on_curve(result.to_affine()?, curve)?;
+    Ok(result)
+}
+
+/// Same as multi_scalar_mult, but leaves the result in Jacobian coordinates.
+pub(crate) fn multi_scalar_mult_jacobian(terms: &[(U256, Points)], curve: &EllipticCurve) -> Result<JacobianPoint, Errors> {
+    let mut reduced = Vec::with_capacity(terms.len());
+    for (k, point) in terms.iter() {
+        let point = check_if_on_curve(*point, curve)?;
+        let k = *k % curve.n;
+        if k != U256::zero() && point != ECpoint::Infinity {
+            reduced.push((k, point));
+        }
+    }
+    match reduced.len() < PIPPENGER_THRESHOLD {
+        true => Ok(strauss(&reduced, curve)),
+        false => Ok(pippenger(&reduced, curve))
+    }
+}
+
+fn strauss(terms: &[(U256, ECpoint)], curve: &EllipticCurve) -> JacobianPoint {
+    let terms: Vec<(Vec<i64>, Vec<JacobianPoint>)> = terms.iter()
+        .map(|(k, point)| (wnaf(*k, STRAUSS_WINDOW), odd_multiples(point, STRAUSS_WINDOW, curve)))
+        .collect();
+    let length = terms.iter().map(|(digits, _)| digits.len()).max().unwrap_or(0);
+    let mut result = JacobianPoint::infinity(curve);
+    for i in (0..length).rev() {
+        result = jacobian_double(&result, curve);
+        for (digits, table) in terms.iter() {
+            match digits.get(i) {
+                Some(digit) if *digit > 0 => result = jacobian_add(&result, &table[(*digit as usize - 1) / 2], curve),
+                Some(digit) if *digit < 0 => result = jacobian_add(&result, &-table[((-*digit) as usize - 1) / 2], curve),
+                _ => ()
+            }
+        }
+    }
+    result
+}
+
+fn pippenger(terms: &[(U256, ECpoint)], curve: &EllipticCurve) -> JacobianPoint {
+    // window width grows with log2 of the number of terms
+    let width = std::cmp::max(2, (usize::BITS - terms.len().leading_zeros()) as usize - 2);
+    let windows = curve.n.bits().div_ceil(width);
+    let mut result = JacobianPoint::infinity(curve);
+    for window in (0..windows).rev() {
+        for _ in 0..width {
+            result = jacobian_double(&result, curve);
+        }
+        // bucket d collects the points whose scalar has digit d + 1 in this window
+        let mut buckets = vec![JacobianPoint::infinity(curve); (1 << width) - 1];
+        for (k, point) in terms.iter() {
+            let digit = ((*k >> (window * width)).low_u64() & ((1u64 << width) - 1)) as usize;
+            if digit != 0 {
+                buckets[digit - 1] = jacobian_add_mixed(&buckets[digit - 1], point, curve);
+            }
+        }
+        // sum of d * bucket[d] via running sums
+        let mut running = JacobianPoint::infinity(curve);
+        let mut window_sum = JacobianPoint::infinity(curve);
+        for bucket in buckets.iter().rev() {
+            running = jacobian_add(&running, bucket, curve);
+            window_sum = jacobian_add(&window_sum, &running, curve);
+        }
+        result = jacobian_add(&result, &window_sum, curve);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Point, Scalar};
+    use crate::ec_math::{point_add, scalar_mult};
+
+    #[test]
+    fn test_multi_scalar_mult() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let G = Points::FinitePoint(Point::from(secp256k1.g));
+        //empty sum is 0
+        assert_eq!(multi_scalar_mult(&[], &secp256k1)?, ECpoint::Infinity);
+        //k * G + (-k) * G = 0
+        let k = U256::from(12345);
+        assert_eq!(multi_scalar_mult(&[(k, G), (secp256k1.n - k, G)], &secp256k1)?, ECpoint::Infinity);
+        // a point not on curve is rejected
+        let not_on_curve = Point::new(secp256k1.g.0, secp256k1.g.1 + U256::one());
+        assert_eq!(multi_scalar_mult(&[(k, Points::from(not_on_curve))], &secp256k1), Err(Errors::PointNotOnCurve(not_on_curve)));
+        // both methods agree with the sum of single scalar multiplications
+        let mut terms = Vec::new();
+        let mut expected = ECpoint::Infinity;
+        let mut k = U256::from_dec_str("12312385769684547396095365029355369071957339694349689622296638024179682296192").unwrap();
+        let mut point = G;
+        for i in 0..(PIPPENGER_THRESHOLD + 6) {
+            terms.push((k, point));
+            expected = point_add(&expected.into(), &scalar_mult(k, &point, &secp256k1)?.into(), &secp256k1)?;
+            if i == 1 || i == 10 || i == PIPPENGER_THRESHOLD + 5 {
+                assert_eq!(multi_scalar_mult(&terms, &secp256k1)?, expected);
+            }
+            k = (Scalar::new(k, &secp256k1) * Scalar::new(U256::from(3), &secp256k1) + Scalar::new(U256::from(i), &secp256k1)).value();
+            point = scalar_mult(U256::from(7), &point, &secp256k1)?.into();
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,5 @@
-use crate::types::{U512, Point, Points, ECpoint, Errors, EllipticCurve};
-use crate::group_math::{a_inverse_mod, m_inverse_mod};
-use crate::ec_math::{check_if_on_curve, point_neg};
+use crate::types::{FieldElement, Point, Points, ECpoint, Errors, EllipticCurve};
+use crate::ec_math::check_if_on_curve;
 
 /// Returns the result of point1 + point2 on curve according to the group law.
 pub fn point_add(point1: &Points, point2: &Points, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
@@ -11,43 +10,29 @@ pub fn point_add(point1: &Points, point2: &Points, curve: &EllipticCurve) -> Res
         (ECpoint::Infinity, ECpoint::OnCurve(p2)) => Ok(ECpoint::OnCurve(p2)),
         (ECpoint::OnCurve(p1), ECpoint::Infinity) => Ok(ECpoint::OnCurve(p1)),
         (ECpoint::OnCurve(p1), ECpoint::OnCurve(p2)) => {
-            let Point { x: x1, y: y1} = p1;
-            let Point { x: x2, y: y2} = p2;
-            let x1: U512 = x1.into();
-            let x2: U512 = x2.into();
-            let y1: U512 = y1.into();
-            let y2: U512 = y2.into();
-            let p: U512 = curve.p.into();
-            let minus_x1: U512 = a_inverse_mod(x1, p)?.into();
-            let minus_x2: U512 = a_inverse_mod(x2, p)?.into();
-            let minus_y2: U512 = a_inverse_mod(y2, p)?.into();
-            if x1 == x2 && y1 != y2 {
+            let x1 = FieldElement::new(p1.x, curve.p);
+            let y1 = FieldElement::new(p1.y, curve.p);
+            let x2 = FieldElement::new(p2.x, curve.p);
+            let y2 = FieldElement::new(p2.y, curve.p);
+            if x1 == x2 && (y1 != y2 || y1.is_zero()) {
                 // point +(-point) = 0
                 return Ok(ECpoint::Infinity);
             }
-            let m: U512;
-            if x1 == x2 {
+            let m = match x1 == x2 {
                 // point1 == point2
                 // m = (3 * x1 * x1 + curve.a) * inverse_mod(2 * y1, curve.p)
-                let x1_2 = (x1 * x1) % p;
-                let x1_2_times_3: U512 = (U512::from(3) * x1_2) % p;
-                let x1_2_times_3_plus_a = (x1_2_times_3 + U512::from(curve.a)) % p;
-                let y1_times_2: U512 = (y1 * U512::from(2)) % p;
-                let inverse_y1_times_2: U512 = m_inverse_mod(y1_times_2, p)?.into();
-                m = x1_2_times_3_plus_a * inverse_y1_times_2;
-            }
-            else {
+                true => {
+                    let three = FieldElement::new(3.into(), curve.p);
+                    let a = FieldElement::from_i64(curve.a, curve.p);
+                    (three * x1.square() + a) * (y1 + y1).inverse()?
+                },
                 // This is the case point1 != point2.
                 // m = (y1 - y2) * inverse_mod(x1 - x2, curve.p)
-                let y1_minus_y2 = (y1 + minus_y2) % p;
-                let x1_minus_x2 = (x1 + minus_x2) % p;
-                let inverse_x1_minus_x2: U512 = m_inverse_mod(x1_minus_x2, p)?.into();
-                m = y1_minus_y2 * inverse_x1_minus_x2;
-            }
-            let m = m % p;
-            let x3 = ((m * m) + minus_x1 + minus_x2) % p;
-            let y3 = (y1 + m * ((x3 + minus_x1) % p)) % p;
-            Ok(point_neg(&Points::FinitePoint(Point::new(x3.into(), y3.into())), curve)?)
+                false => (y1 - y2) * (x1 - x2).inverse()?
+            };
+            let x3 = m.square() - x1 - x2;
+            let y3 = m * (x1 - x3) - y1;
+            Ok(ECpoint::OnCurve(Point::new(x3.value(), y3.value())))
         }
     }
 }
@@ -56,6 +41,7 @@ pub fn point_add(point1: &Points, point2: &Points, curve: &EllipticCurve) -> Res
 mod tests {
     use super::*;
     use crate::types::U256;
+    use crate::group_math::a_inverse_mod;
     #[test]
     fn test_point_add() -> Result<(), Errors> {
         let secp256k1 = EllipticCurve::secp256k1_factory();
@@ -1,5 +1,4 @@
-use crate::types::{Point, Points, EllipticCurve, ECpoint, Errors};
-use crate::group_math::a_inverse_mod;
+use crate::types::{FieldElement, Point, Points, EllipticCurve, ECpoint, Errors};
 use crate::ec_math::check_if_on_curve;
 
 /// Returns the negative of P(x, y) as P'(x, -y) or Errors
@@ -9,7 +8,7 @@ pub fn point_neg(p: &Points, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
         Points::FinitePoint(p) => {
             match check_if_on_curve(*p, curve){
                 Ok(_) => {
-                    let result = Point::new(p.x, a_inverse_mod(p.y, curve.p)?);
+                    let result = Point::new(p.x, (-FieldElement::new(p.y, curve.p)).value());
                     match check_if_on_curve(result, curve){
                         Ok(_) => Ok(ECpoint::OnCurve(result)),
                         Err(_) => Err(Errors::NegativePointNotOnCurve(result))
@@ -25,6 +24,7 @@ pub fn point_neg(p: &Points, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
 mod tests {
     use super::*;
     use crate::types::U256;
+    use crate::group_math::a_inverse_mod;
     #[test]
     fn test_point_neg() {
         let secp256k1 = EllipticCurve::secp256k1_factory();
@@ -1,29 +1,58 @@
-use crate::types::{U256, Points, EllipticCurve, ECpoint, Errors};
-use crate::ec_math::{point_add, check_if_on_curve};
+use crate::types::{U256, Points, EllipticCurve, ECpoint, Errors, JacobianPoint};
+use crate::ec_math::{check_if_on_curve, point_add, point_neg, jacobian_double, jacobian_add_mixed};
+use crate::group_math::decompose_scalar;
 
-///Returns k * point computed by the double and point_add algorithm
+///Returns k * point computed by the double and add algorithm.
+///The intermediate points are kept in Jacobian coordinates, so only the final conversion inverts.
+///If the curve has an endomorphism, k * point = k1 * point + k2 * phi(point) is computed
+///with k1, k2 half as long as k, which halves the number of doublings.
+///It branches on the bits of k, use scalar_mult_ladder when k is secret.
 pub fn scalar_mult(k: U256, point: &Points, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
     let point = check_if_on_curve(*point, curve)?;
-    if k % curve.n == U256::zero() {
-        return Ok(ECpoint::Infinity)
-    }
-    if point == ECpoint::Infinity {
-        return Ok(ECpoint::Infinity)
+    if k % curve.n == U256::zero() || point == ECpoint::Infinity {
+        Ok(ECpoint::Infinity)
     }
     else {
-        let mut result = ECpoint::Infinity;
-        let mut addend = point;
-        let mut bits = k;
-        while bits != U256::zero() {
-            if bits & U256::one() == U256::one(){
-                //Add
-                result = point_add(&result.into(), &addend.into(), curve)?;
+        let result = match curve.endomorphism {
+            Some(endomorphism) => {
+                let ((k1, k1_negative), (k2, k2_negative)) = decompose_scalar(k, curve)?;
+                let p1 = match k1_negative {
+                    true => point_neg(&point.into(), curve)?,
+                    false => point
+                };
+                let p2 = endomorphism.map_point(&point, curve.p);
+                let p2 = match k2_negative {
+                    true => point_neg(&p2.into(), curve)?,
+                    false => p2
+                };
+                // Shamir's trick: one shared chain of doublings for both halves
+                let p1_plus_p2 = point_add(&p1.into(), &p2.into(), curve)?;
+                let mut result = JacobianPoint::infinity(curve);
+                for i in (0..std::cmp::max(k1.bits(), k2.bits())).rev() {
+                    result = jacobian_double(&result, curve);
+                    match (k1.bit(i), k2.bit(i)) {
+                        (true, true) => result = jacobian_add_mixed(&result, &p1_plus_p2, curve),
+                        (true, false) => result = jacobian_add_mixed(&result, &p1, curve),
+                        (false, true) => result = jacobian_add_mixed(&result, &p2, curve),
+                        (false, false) => ()
+                    }
+                }
+                result
+            },
+            None => {
+                let mut result = JacobianPoint::infinity(curve);
+                for i in (0..k.bits()).rev() {
+                    //Double
+                    result = jacobian_double(&result, curve);
+                    if k.bit(i) {
+                        //Add
+                        result = jacobian_add_mixed(&result, &point, curve);
+                    }
+                }
+                result
             }
-            //Double
-            addend = point_add(&addend.into(), &addend.into(), curve)?;
-            bits = bits >> 1;
-        }
-        let result = check_if_on_curve(result, curve)?;
+        };
+        let result = check_if_on_curve(result.to_affine()?, curve)?;
         Ok(result)
     }
 }
@@ -72,5 +101,12 @@ mod tests {
         let result_y = U256::from_dec_str("109236945745669593534474897756172178689381177381602435107906663179476813370855").unwrap();
         let result = Point::new(result_x, result_y);
         assert_eq!(scalar_mult(max, &G, &secp256k1), Ok(ECpoint::OnCurve(result)));
+        //the endomorphism does not change any result
+        let mut no_endomorphism = EllipticCurve::secp256k1_factory();
+        no_endomorphism.endomorphism = None;
+        let p = scalar_mult(U256::from(255), &G, &secp256k1).unwrap().into();
+        for k in [U256::one(), U256::from(255), secp256k1.n - U256::one(), max].iter() {
+            assert_eq!(scalar_mult(*k, &p, &secp256k1), scalar_mult(*k, &p, &no_endomorphism));
+        }
     }
 }
@@ -0,0 +1,51 @@
+use crate::types::{U256, EllipticCurve, ECpoint, Errors, JacobianPoint};
+use crate::types::generator_table::WINDOW_BITS;
+use crate::ec_math::{check_if_on_curve, jacobian_add, jacobian_add_mixed};
+
+///Returns k * G using the precomputed generator table of the curve.
+///One table entry is added per 4 bits of k. Entries are looked up without
+///secret dependent memory access and additions of digit 0 are discarded by masking,
+///so every k runs the same number of group operations. This is not fully constant time:
+///the reduction k % n, the field arithmetic of U256/U512 and the infinity and h == 0 cases
+///of jacobian_add still branch.
+pub fn scalar_mult_base(k: U256, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+    let table = curve.generator_table();
+    let k = k % curve.n;
+    let mut result = table.blinding();
+    for window in 0..table.len() {
+        let digit = ((k >> (window * WINDOW_BITS)).low_u64() & ((1u64 << WINDOW_BITS) - 1)) as usize;
+        let mut sum = jacobian_add_mixed(&result, &table.select(window, digit), curve);
+        JacobianPoint::conditional_swap(&mut result, &mut sum, digit != 0);
+    }
+    let result = jacobian_add(&result, &-table.blinding(), curve);
+    let result = check_if_on_curve(result.to_affine()?, curve)?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Points, Point};
+    use crate::ec_math::scalar_mult;
+
+    #[test]
+    fn test_scalar_mult_base() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let G = Points::FinitePoint(Point::from(secp256k1.g));
+        let ks = [
+            U256::zero(),
+            U256::one(),
+            U256::from(2),
+            U256::from(255),
+            secp256k1.n,
+            secp256k1.n - U256::one(),
+            U256::from_dec_str("12312385769684547396095365029355369071957339694349689622296638024179682296192").unwrap(),
+            U256::MAX,
+        ];
+        for k in ks.iter() {
+            assert_eq!(scalar_mult_base(*k, &secp256k1)?, scalar_mult(*k, &G, &secp256k1)?);
+        }
+        Ok(())
+    }
+}
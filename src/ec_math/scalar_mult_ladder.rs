@@ -0,0 +1,70 @@
+use crate::types::{U256, U512, Points, EllipticCurve, ECpoint, Errors, JacobianPoint};
+use crate::ec_math::{check_if_on_curve, jacobian_add, jacobian_double};
+
+///Returns k * point computed by the Montgomery ladder, the point must lie in the subgroup of order n.
+///Every k runs the same fixed sequence of group operations (one addition and one doubling per bit)
+///and the points are swapped by masking instead of branching on the bits of k. This is not fully
+///constant time: the reduction of k and the field arithmetic of U256/U512 still branch.
+pub fn scalar_mult_ladder(k: U256, point: &Points, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+    let point = check_if_on_curve(*point, curve)?;
+    if point == ECpoint::Infinity {
+        return Ok(ECpoint::Infinity);
+    }
+    // k + n or k + 2n has exactly n.bits() + 1 bits, so the ladder always runs the
+    // same number of steps and never starts from infinity. n * point = 0, so the result is k * point.
+    let bits = curve.n.bits();
+    let n = U512::from(curve.n);
+    let k_plus_n = U512::from(k % curve.n) + n;
+    let k_plus_2n = k_plus_n + n;
+    let mask = 0u64.wrapping_sub(k_plus_n.bit(bits) as u64);
+    let mut k = U512::zero();
+    for i in 0..8 {
+        k.0[i] = (k_plus_n.0[i] & mask) | (k_plus_2n.0[i] & !mask);
+    }
+    let mut r0 = JacobianPoint::from_affine(&point, curve);
+    let mut r1 = jacobian_double(&r0, curve);
+    for i in (0..bits).rev() {
+        // invariant: r1 - r0 = point
+        let bit = k.bit(i);
+        JacobianPoint::conditional_swap(&mut r0, &mut r1, bit);
+        r1 = jacobian_add(&r0, &r1, curve);
+        r0 = jacobian_double(&r0, curve);
+        JacobianPoint::conditional_swap(&mut r0, &mut r1, bit);
+    }
+    let result = check_if_on_curve(r0.to_affine()?, curve)?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Point;
+    use crate::ec_math::scalar_mult;
+
+    #[test]
+    fn test_scalar_mult_ladder() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let G = Points::FinitePoint(Point::from(secp256k1.g));
+        //k * 0 = 0
+        assert_eq!(scalar_mult_ladder(U256::from(5), &Points::Infinity, &secp256k1), Ok(ECpoint::Infinity));
+        let max = U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007913129639935").unwrap();
+        let ks = [
+            U256::zero(),
+            U256::one(),
+            U256::from(2),
+            U256::from(255),
+            secp256k1.n,
+            secp256k1.n - U256::one(),
+            U256::from_dec_str("12312385769684547396095365029355369071957339694349689622296638024179682296192").unwrap(),
+            max,
+        ];
+        // the ladder agrees with double and add
+        for k in ks.iter() {
+            assert_eq!(scalar_mult_ladder(*k, &G, &secp256k1)?, scalar_mult(*k, &G, &secp256k1)?);
+        }
+        let p = scalar_mult(U256::from(255), &G, &secp256k1)?.into();
+        assert_eq!(scalar_mult_ladder(max, &p, &secp256k1)?, scalar_mult(max, &p, &secp256k1)?);
+        Ok(())
+    }
+}
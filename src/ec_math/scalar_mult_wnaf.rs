@@ -0,0 +1,130 @@
+use crate::types::{U256, U512, Points, EllipticCurve, ECpoint, Errors, JacobianPoint};
+use crate::ec_math::{check_if_on_curve, jacobian_add, jacobian_double};
+
+/// Returns the width-w non-adjacent form of k, least significant digit first.
+/// Every non-zero digit is odd and smaller than 2^(w-1) in absolute value, and
+/// out of any w consecutive digits at most one is non-zero.
+pub(crate) fn wnaf(k: U256, w: usize) -> Vec<i64> {
+    let window = 1i64 << w;
+    let mut k = U512::from(k);
+    let mut digits = Vec::with_capacity(k.bits() + 1);
+    while k != U512::zero() {
+        let mut digit = 0;
+        if k.bit(0) {
+            digit = (k.low_u64() & (window as u64 - 1)) as i64;
+            if digit >= window / 2 {
+                digit -= window;
+            }
+            k = match digit > 0 {
+                true => k - U512::from(digit as u64),
+                false => k + U512::from((-digit) as u64)
+            };
+        }
+        digits.push(digit);
+        k >>= 1;
+    }
+    digits
+}
+
+/// Returns the odd multiples point, 3 * point, ..., (2^(w-1) - 1) * point.
+pub(crate) fn odd_multiples(point: &ECpoint, w: usize, curve: &EllipticCurve) -> Vec<JacobianPoint> {
+    let point = JacobianPoint::from_affine(point, curve);
+    let double = jacobian_double(&point, curve);
+    let mut table = vec![point];
+    for i in 1..(1 << (w - 2)) {
+        table.push(jacobian_add(&table[i - 1], &double, curve));
+    }
+    table
+}
+
+///Returns k * point computed from the width-w NAF of k (2 <= w <= 8).
+///Odd multiples of the point up to (2^(w-1) - 1) * point are precomputed, after that only
+///one addition is needed per w bits of k on average. Runs in variable time, so k must be public.
+pub fn scalar_mult_wnaf(k: U256, point: &Points, w: usize, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+    if !(2..=8).contains(&w) {
+        return Err(Errors::InvalidWindowWidth(w));
+    }
+    let point = check_if_on_curve(*point, curve)?;
+    let k = k % curve.n;
+    if k == U256::zero() || point == ECpoint::Infinity {
+        return Ok(ECpoint::Infinity);
+    }
+    let table = odd_multiples(&point, w, curve);
+    let mut result = JacobianPoint::infinity(curve);
+    for digit in wnaf(k, w).into_iter().rev() {
+        result = jacobian_double(&result, curve);
+        if digit > 0 {
+            result = jacobian_add(&result, &table[(digit as usize - 1) / 2], curve);
+        }
+        if digit < 0 {
+            result = jacobian_add(&result, &-table[((-digit) as usize - 1) / 2], curve);
+        }
+    }
+    let result = check_if_on_curve(result.to_affine()?, curve)?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Point;
+    use crate::ec_math::scalar_mult;
+
+    #[test]
+    fn test_wnaf() {
+        let k = U256::from_dec_str("12312385769684547396095365029355369071957339694349689622296638024179682296192").unwrap();
+        let max = U256::MAX;
+        for k in [U256::one(), U256::from(255), k, max].iter() {
+            for w in 2..=8 {
+                let digits = wnaf(*k, w);
+                // digits are odd, bounded and non adjacent
+                for (i, d) in digits.iter().enumerate() {
+                    if *d != 0 {
+                        assert_eq!(d.abs() % 2, 1);
+                        assert!(d.abs() < 1 << (w - 1));
+                        assert!(digits[i + 1..].iter().take(w - 1).all(|d| *d == 0));
+                    }
+                }
+                // sum of digit * 2^i gives back k
+                let (mut positive, mut negative) = (U512::zero(), U512::zero());
+                for (i, d) in digits.iter().enumerate() {
+                    match *d > 0 {
+                        true => positive += U512::from(*d as u64) << i,
+                        false => negative += U512::from((-*d) as u64) << i
+                    }
+                }
+                assert_eq!(positive - negative, U512::from(*k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_mult_wnaf() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let G = Points::FinitePoint(Point::from(secp256k1.g));
+        assert_eq!(scalar_mult_wnaf(U256::one(), &G, 1, &secp256k1), Err(Errors::InvalidWindowWidth(1)));
+        assert_eq!(scalar_mult_wnaf(U256::one(), &G, 9, &secp256k1), Err(Errors::InvalidWindowWidth(9)));
+        //k * 0 = 0
+        assert_eq!(scalar_mult_wnaf(U256::from(5), &Points::Infinity, 4, &secp256k1), Ok(ECpoint::Infinity));
+        let ks = [
+            U256::zero(),
+            U256::one(),
+            U256::from(2),
+            U256::from(255),
+            secp256k1.n,
+            secp256k1.n - U256::one(),
+            U256::from_dec_str("12312385769684547396095365029355369071957339694349689622296638024179682296192").unwrap(),
+            U256::MAX,
+        ];
+        let p = scalar_mult(U256::from(255), &G, &secp256k1)?.into();
+        // every window width agrees with double and add
+        for w in 2..=8 {
+            for k in ks.iter() {
+                assert_eq!(scalar_mult_wnaf(*k, &G, w, &secp256k1)?, scalar_mult(*k, &G, &secp256k1)?);
+                assert_eq!(scalar_mult_wnaf(*k, &p, w, &secp256k1)?, scalar_mult(*k, &p, &secp256k1)?);
+            }
+        }
+        Ok(())
+    }
+}
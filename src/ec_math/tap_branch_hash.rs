@@ -0,0 +1,26 @@
+use crate::hashing::tagged_hash;
+
+/// Returns the BIP341 branch hash tagged_hash("TapBranch", a || b) of two child hashes,
+/// the children are sorted first so the order they are given in does not matter.
+pub fn tap_branch_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    match a <= b {
+        true => tagged_hash("TapBranch", &[a, b]),
+        false => tagged_hash("TapBranch", &[b, a])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_branch_hash() {
+        // the two leaves and the merkle root of BIP341 scriptPubKey vector 3
+        let mut a = [0u8; 32];
+        a.copy_from_slice(&hex::decode("8ad69ec7cf41c2a4001fd1f738bf1e505ce2277acdcaa63fe4765192497f47a7").unwrap());
+        let mut b = [0u8; 32];
+        b.copy_from_slice(&hex::decode("f224a923cd0021ab202ab139cc56802ddb92dcfc172b9212261a539df79a112a").unwrap());
+        assert_eq!(hex::encode(tap_branch_hash(&a, &b)), "6c2dc106ab816b73f9d07e3cd1ef2c8c1256f519748e0813e4edd2405d277bef");
+        assert_eq!(tap_branch_hash(&b, &a), tap_branch_hash(&a, &b));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::types::{TapLeaf, TapTree, ControlBlock, XOnlyPublicKey, Errors};
+use crate::ec_math::tap_merkle_root;
+
+/// Returns every leaf of the tree (from left to right) with the control block that spends it
+/// through the output key of `internal_key` and the tree. `output_key_parity` is the parity of that
+/// output key, as returned by taproot_tweak_public_key. Returns TapTreeTooDeep if a path is longer than 128.
+pub fn tap_control_blocks(internal_key: &XOnlyPublicKey, tree: &TapTree, output_key_parity: bool) -> Result<Vec<(TapLeaf, ControlBlock)>, Errors> {
+    if tree.depth() > ControlBlock::MAX_PATH_LENGTH {
+        return Err(Errors::TapTreeTooDeep(tree.depth()));
+    }
+    Ok(merkle_paths(tree).into_iter().map(|(leaf, merkle_path)| {
+        let control_block = ControlBlock {
+            leaf_version: leaf.version,
+            output_key_parity,
+            internal_key: *internal_key,
+            merkle_path
+        };
+        (leaf.clone(), control_block)
+    }).collect())
+}
+
+// sibling hashes from each leaf up to the root
+fn merkle_paths(tree: &TapTree) -> Vec<(&TapLeaf, Vec<[u8; 32]>)> {
+    match tree {
+        TapTree::Leaf(leaf) => vec![(leaf, Vec::new())],
+        TapTree::Branch(left, right) => {
+            let (left_hash, right_hash) = (tap_merkle_root(left), tap_merkle_root(right));
+            let mut paths = merkle_paths(left);
+            for (_, path) in paths.iter_mut() {
+                path.push(right_hash);
+            }
+            for (leaf, mut path) in merkle_paths(right) {
+                path.push(left_hash);
+                paths.push((leaf, path));
+            }
+            paths
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ECpoint, Point, EllipticCurve};
+    use crate::ec_math::{tap_leaf_hash, taproot_tweak_public_key};
+
+    // parses the notation of the vector file: a leaf is version:script, a branch is [left;right]
+    fn parse_tree(text: &str) -> (TapTree, &str) {
+        match text.strip_prefix('[') {
+            Some(rest) => {
+                let (left, rest) = parse_tree(rest);
+                let (right, rest) = parse_tree(rest.strip_prefix(';').unwrap());
+                (TapTree::branch(left, right), rest.strip_prefix(']').unwrap())
+            }
+            None => {
+                let end = text.find([';', ']']).unwrap_or(text.len());
+                let (version, script) = text[..end].split_at(2);
+                let leaf = TapLeaf::new(u8::from_str_radix(version, 16).unwrap(), hex::decode(&script[1..]).unwrap()).unwrap();
+                (TapTree::Leaf(leaf), &text[end..])
+            }
+        }
+    }
+
+    #[test]
+    fn test_tap_control_blocks() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // scriptPubKey vectors of the BIP341 wallet test vectors that have a script tree
+        let vectors = include_str!("../../test_vectors/bip341_script_tree.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let mut key = [0u8; 32];
+            key.copy_from_slice(&hex::decode(fields[1]).unwrap());
+            let internal_key = XOnlyPublicKey::from_bytes(&key, &secp256k1)?;
+            let (tree, rest) = parse_tree(fields[2]);
+            assert!(rest.is_empty());
+            let leaf_hashes: Vec<String> = tree.leaves().iter().map(|leaf| hex::encode(tap_leaf_hash(leaf))).collect();
+            assert_eq!(leaf_hashes.join(" "), fields[3], "leaf hashes of vector {}", fields[0]);
+            assert_eq!(hex::encode(tap_merkle_root(&tree)), fields[4], "merkle root of vector {}", fields[0]);
+            let (_, parity) = taproot_tweak_public_key(&internal_key, Some(&tap_merkle_root(&tree)), &secp256k1)?;
+            let control_blocks: Vec<String> = tap_control_blocks(&internal_key, &tree, parity)?.iter()
+                .map(|(_, control_block)| hex::encode(control_block.to_bytes())).collect();
+            assert_eq!(control_blocks.join(" "), fields[5], "control blocks of vector {}", fields[0]);
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        // a chain of 129 branches can't be spent
+        let mut tree = TapTree::Leaf(TapLeaf::tapscript(vec![0x51]));
+        for _ in 0..129 {
+            tree = TapTree::branch(tree, TapTree::Leaf(TapLeaf::tapscript(vec![0x52])));
+        }
+        let internal_key = XOnlyPublicKey::from_point(&ECpoint::OnCurve(Point::from(secp256k1.g)), &secp256k1)?;
+        assert_eq!(tap_control_blocks(&internal_key, &tree, false), Err(Errors::TapTreeTooDeep(129)));
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::types::TapLeaf;
+use crate::hashing::{tagged_hash, compact_size};
+
+/// Returns the BIP341 leaf hash tagged_hash("TapLeaf", version || compact_size(len(script)) || script).
+pub fn tap_leaf_hash(leaf: &TapLeaf) -> [u8; 32] {
+    tagged_hash("TapLeaf", &[&[leaf.version], &compact_size(leaf.script.len() as u64), &leaf.script])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_leaf_hash() {
+        // single leaf of BIP341 scriptPubKey vector 1, its hash is also the merkle root
+        let leaf = TapLeaf::tapscript(hex::decode("20d85a959b0290bf19bb89ed43c916be835475d013da4b362117393e25a48229b8ac").unwrap());
+        assert_eq!(hex::encode(tap_leaf_hash(&leaf)), "5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21");
+    }
+}
@@ -0,0 +1,26 @@
+use crate::types::TapTree;
+use crate::ec_math::{tap_leaf_hash, tap_branch_hash};
+
+/// Returns the merkle root of a script tree, the value `taproot_tweak_public_key` commits to.
+pub fn tap_merkle_root(tree: &TapTree) -> [u8; 32] {
+    match tree {
+        TapTree::Leaf(leaf) => tap_leaf_hash(leaf),
+        TapTree::Branch(left, right) => tap_branch_hash(&tap_merkle_root(left), &tap_merkle_root(right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TapLeaf;
+
+    #[test]
+    fn test_tap_merkle_root() {
+        // BIP341 scriptPubKey vector 3: a tapscript and a leaf of the unknown version 0xfa
+        let left = TapLeaf::tapscript(hex::decode("20387671353e273264c495656e27e39ba899ea8fee3bb69fb2a680e22093447d48ac").unwrap());
+        let right = TapLeaf::new(0xfa, hex::decode("06424950333431").unwrap()).unwrap();
+        assert_eq!(tap_merkle_root(&TapTree::Leaf(left.clone())), tap_leaf_hash(&left));
+        let tree = TapTree::branch(TapTree::Leaf(left), TapTree::Leaf(right));
+        assert_eq!(hex::encode(tap_merkle_root(&tree)), "6c2dc106ab816b73f9d07e3cd1ef2c8c1256f519748e0813e4edd2405d277bef");
+    }
+}
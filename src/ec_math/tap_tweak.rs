@@ -0,0 +1,37 @@
+use crate::types::{Scalar, XOnlyPublicKey, EllipticCurve, Errors};
+use crate::hashing::tagged_hash;
+
+/// Returns the BIP341 tweak t = tagged_hash("TapTweak", P || merkle_root) of the internal key P.
+/// Without a script tree (`merkle_root` is None) only P is hashed. Returns ScalarOutOfRange if t is not below n.
+pub fn tap_tweak(internal_key: &XOnlyPublicKey, merkle_root: Option<&[u8; 32]>, curve: &EllipticCurve) -> Result<Scalar, Errors> {
+    let hash = match merkle_root {
+        Some(root) => tagged_hash("TapTweak", &[&internal_key.to_bytes(), root]),
+        None => tagged_hash("TapTweak", &[&internal_key.to_bytes()])
+    };
+    Scalar::from_bytes(&hash, curve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+
+    #[test]
+    fn test_tap_tweak() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // first two scriptPubKey vectors of BIP341, without and with a script tree
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&hex::decode("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d").unwrap());
+        let key = XOnlyPublicKey::from_bytes(&key, &secp256k1)?;
+        let tweak = tap_tweak(&key, None, &secp256k1)?;
+        assert_eq!(tweak.value(), "b86e7be8f39bab32a6f2c0443abbc210f0edac0e2c53d501b36b64437d9c6c70".parse::<U256>().unwrap());
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&hex::decode("187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27").unwrap());
+        let key = XOnlyPublicKey::from_bytes(&key, &secp256k1)?;
+        let mut root = [0u8; 32];
+        root.copy_from_slice(&hex::decode("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21").unwrap());
+        let tweak = tap_tweak(&key, Some(&root), &secp256k1)?;
+        assert_eq!(tweak.value(), "cbd8679ba636c1110ea247542cfbd964131a6be84f873f7f3b62a777528ed001".parse::<U256>().unwrap());
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::types::{ECpoint, XOnlyPublicKey, EllipticCurve, Errors};
+use crate::ec_math::{point_add, scalar_mult_base, tap_tweak};
+
+/// Tweaks the BIP341 internal key P into the output key Q = P + t * G, t is given by `tap_tweak`.
+/// Returns the x only output key and the parity of Q (true if y is odd), which the control block of a
+/// script path spend has to carry. Returns PointAtInfinity if Q is the point at infinity.
+pub fn taproot_tweak_public_key(internal_key: &XOnlyPublicKey, merkle_root: Option<&[u8; 32]>, curve: &EllipticCurve) -> Result<(XOnlyPublicKey, bool), Errors> {
+    let t = tap_tweak(internal_key, merkle_root, curve)?;
+    #[allow(non_snake_case)]
+    let Q = point_add(&internal_key.to_point().into(), &scalar_mult_base(t.value(), curve)?.into(), curve)?;
+    match Q {
+        ECpoint::Infinity => Err(Errors::PointAtInfinity),
+        ECpoint::OnCurve(point) => Ok((XOnlyPublicKey::from_point(&Q, curve)?, point.y.bit(0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_taproot_tweak_public_key() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // scriptPubKey vectors of the BIP341 wallet test vectors
+        let vectors = include_str!("../../test_vectors/bip341_tweak.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let mut key = [0u8; 32];
+            key.copy_from_slice(&hex::decode(fields[1]).unwrap());
+            let internal_key = XOnlyPublicKey::from_bytes(&key, &secp256k1)?;
+            let mut root = [0u8; 32];
+            let merkle_root = match fields[2].is_empty() {
+                true => None,
+                false => {
+                    root.copy_from_slice(&hex::decode(fields[2]).unwrap());
+                    Some(&root)
+                }
+            };
+            assert_eq!(hex::encode(tap_tweak(&internal_key, merkle_root, &secp256k1)?.to_bytes()), fields[3], "tweak of vector {}", fields[0]);
+            let (output_key, parity) = taproot_tweak_public_key(&internal_key, merkle_root, &secp256k1)?;
+            assert_eq!(hex::encode(output_key.to_bytes()), fields[4], "output key of vector {}", fields[0]);
+            // segwit v1 program: OP_1 PUSH32 <Q>
+            assert_eq!(format!("5120{}", hex::encode(output_key.to_bytes())), fields[5], "scriptPubKey of vector {}", fields[0]);
+            // the first byte of the control blocks is the leaf version 0xc0 with the parity of Q in the lowest bit,
+            // vector 0 has no script tree and thus no control block
+            if !fields[6].is_empty() {
+                assert_eq!(parity, fields[6] == "1", "parity of vector {}", fields[0]);
+            }
+            count += 1;
+        }
+        assert_eq!(count, 7);
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::types::{TapLeaf, ControlBlock, XOnlyPublicKey, EllipticCurve, Errors};
+use crate::ec_math::{tap_leaf_hash, tap_branch_hash, taproot_tweak_public_key};
+
+/// Checks that `script` with the control block is committed to by `output_key` (BIP341 script path):
+/// the leaf hash folded with the merkle path gives a root that tweaks the internal key into the
+/// output key, with the parity given in the control block.
+pub fn verify_control_block(output_key: &XOnlyPublicKey, script: &[u8], control_block: &ControlBlock, curve: &EllipticCurve) -> Result<bool, Errors> {
+    if control_block.merkle_path.len() > ControlBlock::MAX_PATH_LENGTH {
+        return Ok(false);
+    }
+    let leaf = TapLeaf::new(control_block.leaf_version, script.to_vec())?;
+    let root = control_block.merkle_path.iter()
+        .fold(tap_leaf_hash(&leaf), |hash, sibling| tap_branch_hash(&hash, sibling));
+    let (key, parity) = taproot_tweak_public_key(&control_block.internal_key, Some(&root), curve)?;
+    Ok(key == *output_key && parity == control_block.output_key_parity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ECpoint, Point, TapTree};
+    use crate::ec_math::{build_tap_tree, tap_control_blocks, tap_merkle_root};
+
+    #[test]
+    fn test_verify_control_block() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let internal_key = XOnlyPublicKey::from_point(&ECpoint::OnCurve(Point::from(secp256k1.g)), &secp256k1)?;
+        let leaves: Vec<TapLeaf> = (0x51..=0x53).map(|op| TapLeaf::tapscript(vec![op])).chain(vec![TapLeaf::new(0xfa, vec![0x54])?]).collect();
+        let tree = build_tap_tree(&leaves)?;
+        let (output_key, parity) = taproot_tweak_public_key(&internal_key, Some(&tap_merkle_root(&tree)), &secp256k1)?;
+        let control_blocks = tap_control_blocks(&internal_key, &tree, parity)?;
+        assert_eq!(control_blocks.len(), 4);
+        for (leaf, control_block) in control_blocks.iter() {
+            assert!(verify_control_block(&output_key, &leaf.script, control_block, &secp256k1)?);
+            // another script, a flipped parity, a wrong version or a wrong path don't verify
+            assert!(!verify_control_block(&output_key, &[0x00], control_block, &secp256k1)?);
+            let mut flipped = control_block.clone();
+            flipped.output_key_parity = !flipped.output_key_parity;
+            assert!(!verify_control_block(&output_key, &leaf.script, &flipped, &secp256k1)?);
+            let mut version = control_block.clone();
+            version.leaf_version ^= 0x02;
+            assert!(!verify_control_block(&output_key, &leaf.script, &version, &secp256k1)?);
+            let mut path = control_block.clone();
+            path.merkle_path.pop();
+            assert!(!verify_control_block(&output_key, &leaf.script, &path, &secp256k1)?);
+        }
+        // a single leaf tree has an empty path
+        let single = TapTree::Leaf(leaves[0].clone());
+        let (output_key, parity) = taproot_tweak_public_key(&internal_key, Some(&tap_merkle_root(&single)), &secp256k1)?;
+        let (_, control_block) = &tap_control_blocks(&internal_key, &single, parity)?[0];
+        assert!(control_block.merkle_path.is_empty());
+        assert!(verify_control_block(&output_key, &leaves[0].script, control_block, &secp256k1)?);
+        Ok(())
+    }
+}
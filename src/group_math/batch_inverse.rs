@@ -0,0 +1,58 @@
+use crate::types::{U256, FieldElement, Errors};
+
+/// Replaces every element of `values` by its multiplicative inverse modulo p, using a single
+/// inversion and 3(n-1) multiplications (Montgomery's trick). p must be a prime.
+/// Elements that are zero modulo p have no inverse, they are set to zero and their positions are returned.
+pub fn batch_inverse(values: &mut [U256], p: U256) -> Result<Vec<usize>, Errors> {
+    if p == U256::zero() {
+        return Err(Errors::ZeroModulo);
+    }
+    let mut zeros = Vec::new();
+    // prefix[i] is the product of the non-zero values before position i
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut product = FieldElement::one(p);
+    for (i, value) in values.iter_mut().enumerate() {
+        prefix.push(product);
+        let element = FieldElement::new(*value, p);
+        *value = element.value();
+        match element.is_zero() {
+            true => zeros.push(i),
+            false => product = product * element
+        }
+    }
+    let mut inverse = product.inverse()?;
+    for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+        if *value != U256::zero() {
+            let element = FieldElement::new(*value, p);
+            *value = (inverse * prefix).value();
+            inverse = inverse * element;
+        }
+    }
+    Ok(zeros)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EllipticCurve;
+    use crate::group_math::m_inverse_mod;
+
+    #[test]
+    fn test_batch_inverse() -> Result<(), Errors> {
+        // 5 has 9, 2 has 6 and 10 has 10 as multiplicative inverse mod 11, 0 and 22 have none
+        let mut values = [U256::from(5), U256::zero(), U256::from(2), U256::from(22), U256::from(10)];
+        assert_eq!(batch_inverse(&mut values, U256::from(11))?, vec![1, 3]);
+        assert_eq!(values, [U256::from(9), U256::zero(), U256::from(6), U256::zero(), U256::from(10)]);
+        // the results match m_inverse_mod
+        let p = EllipticCurve::secp256k1_factory().p;
+        let original: Vec<U256> = (1..20u64).map(|i| U256::from(i).pow(U256::from(40)) + U256::from(i)).collect();
+        let mut values = original.clone();
+        assert_eq!(batch_inverse(&mut values, p)?, vec![]);
+        for (value, inverse) in original.iter().zip(values) {
+            assert_eq!(m_inverse_mod(*value, p)?, inverse);
+        }
+        assert_eq!(batch_inverse(&mut [], p)?, vec![]);
+        assert_eq!(batch_inverse(&mut [U256::one()], U256::zero()), Err(Errors::ZeroModulo));
+        Ok(())
+    }
+}
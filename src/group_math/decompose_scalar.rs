@@ -0,0 +1,60 @@
+use crate::types::{U256, U512, Scalar, EllipticCurve, Errors};
+
+/// A signed half of a split scalar as (magnitude, is_negative).
+pub type SignedHalf = (U256, bool);
+
+/// Splits k into k1 + k2 * lambda (mod n) using the endomorphism of the curve, where k1 and k2
+/// are about half as long as n.
+pub fn decompose_scalar(k: U256, curve: &EllipticCurve) -> Result<(SignedHalf, SignedHalf), Errors> {
+    let endomorphism = curve.endomorphism.ok_or(Errors::NoEndomorphism)?;
+    let n = U512::from(curve.n);
+    let k = U512::from(k % curve.n);
+    // c1 = round(b2 * k / n), c2 = round(-b1 * k / n)
+    let round_div = |a: U512| (a + n / 2) / n;
+    let c1 = Scalar::new(round_div(k * U512::from(endomorphism.b2)).into(), curve);
+    let c2 = Scalar::new(round_div(k * U512::from(endomorphism.minus_b1)).into(), curve);
+    // k2 = -c1 * b1 - c2 * b2, k1 = k - k2 * lambda
+    let k2 = c1 * Scalar::new(endomorphism.minus_b1, curve) - c2 * Scalar::new(endomorphism.b2, curve);
+    let k1 = Scalar::new(k.into(), curve) - k2 * Scalar::new(endomorphism.lambda, curve);
+    let signed = |s: Scalar| match s.value() > curve.n / 2 {
+        true => ((-s).value(), true),
+        false => (s.value(), false)
+    };
+    Ok((signed(k1), signed(k2)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompose_scalar() -> Result<(), Errors> {
+        let mut secp256k1 = EllipticCurve::secp256k1_factory();
+        let lambda = Scalar::new(secp256k1.endomorphism.unwrap().lambda, &secp256k1);
+        let ks = [
+            U256::zero(),
+            U256::one(),
+            secp256k1.n - U256::one(),
+            lambda.value(),
+            U256::from_dec_str("12312385769684547396095365029355369071957339694349689622296638024179682296192").unwrap(),
+            U256::MAX,
+        ];
+        let from_signed = |(v, negative): SignedHalf, curve: &EllipticCurve| match negative {
+            true => -Scalar::new(v, curve),
+            false => Scalar::new(v, curve)
+        };
+        for k in ks.iter() {
+            let (k1, k2) = decompose_scalar(*k, &secp256k1)?;
+            // both halves fit into 129 bits
+            assert!(k1.0.bits() <= 129 && k2.0.bits() <= 129);
+            // k1 + k2 * lambda = k (mod n)
+            let sum = from_signed(k1, &secp256k1) + from_signed(k2, &secp256k1) * lambda;
+            assert_eq!(sum, Scalar::new(*k, &secp256k1));
+        }
+        // lambda splits into (0, 1)
+        assert_eq!(decompose_scalar(lambda.value(), &secp256k1)?, ((U256::zero(), false), (U256::one(), false)));
+        secp256k1.endomorphism = None;
+        assert_eq!(decompose_scalar(U256::one(), &secp256k1), Err(Errors::NoEndomorphism));
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::types::{U256, FieldElement, Errors};
+
+/// Returns the Lagrange coefficient of x_i for interpolating f(0) modulo the prime p from the points
+/// `xs`: the product of x_j / (x_j - x_i) over all x_j != x_i. x_i must be one of `xs`, the points must differ.
+pub fn lagrange_coefficient<T: Into<U256>, U: Into<U256>>(x_i: T, xs: &[U256], p: U) -> Result<U256, Errors> {
+    let (x_i, p) = (x_i.into(), p.into());
+    if p == U256::zero() {
+        return Err(Errors::ZeroModulo);
+    }
+    let x_i = FieldElement::new(x_i, p);
+    if !xs.iter().any(|x| FieldElement::new(*x, p) == x_i) {
+        return Err(Errors::NotInInterpolationSet(x_i.value()));
+    }
+    let mut numerator = FieldElement::one(p);
+    let mut denominator = FieldElement::one(p);
+    let mut seen = false;
+    for x_j in xs.iter().map(|x| FieldElement::new(*x, p)) {
+        if x_j == x_i && !seen {
+            seen = true;
+            continue;
+        }
+        // a repeated x_i makes the denominator zero
+        numerator = numerator * x_j;
+        denominator = denominator * (x_j - x_i);
+    }
+    Ok((numerator * denominator.inverse_vartime()?).value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::group_math::polynomial_evaluate;
+
+    #[test]
+    fn test_lagrange_coefficient() -> Result<(), Errors> {
+        // any 3 points of f(x) = 7 + 4x + 9x^2 mod 97 give back f(0)
+        let p = U256::from(97);
+        let coefficients = [U256::from(7), U256::from(4), U256::from(9)];
+        let xs = [U256::from(1), U256::from(3), U256::from(10)];
+        let mut secret = FieldElement::zero(p);
+        for x in xs.iter() {
+            let y = FieldElement::new(polynomial_evaluate(&coefficients, *x, p)?, p);
+            secret = secret + y * FieldElement::new(lagrange_coefficient(*x, &xs, p)?, p);
+        }
+        assert_eq!(secret.value(), U256::from(7));
+        // 2/(2-1) = 2 and 1/(1-2) = -1
+        let xs = [U256::from(1), U256::from(2)];
+        assert_eq!(lagrange_coefficient(1, &xs, p)?, U256::from(2));
+        assert_eq!(lagrange_coefficient(2, &xs, p)?, U256::from(96));
+        assert_eq!(lagrange_coefficient(5, &xs, p), Err(Errors::NotInInterpolationSet(U256::from(5))));
+        assert_eq!(lagrange_coefficient(1, &[U256::from(1), U256::from(1)], p), Err(Errors::ZeroDivision));
+        Ok(())
+    }
+}
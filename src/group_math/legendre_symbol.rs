@@ -0,0 +1,39 @@
+use crate::types::{U256, Errors, FieldElement};
+
+/// Returns the Legendre symbol (a / p) of a modulo the odd prime p, using Euler's criterion a^((p-1)/2):
+/// 1 if a is a non-zero quadratic residue, -1 if it is not a quadratic residue and 0 if a is 0 mod p.
+pub fn legendre_symbol<T: Into<U256>, U: Into<U256>>(a: T, p: U) -> Result<i8, Errors> {
+    let (a, p) = (a.into(), p.into());
+    if p == U256::zero() {
+        return Err(Errors::ZeroModulo);
+    }
+    let a = FieldElement::new(a, p);
+    if a.is_zero() {
+        return Ok(0);
+    }
+    match a.pow((p - U256::one()) >> 1) == FieldElement::one(p) {
+        true => Ok(1),
+        false => Ok(-1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EllipticCurve;
+
+    #[test]
+    fn test_legendre_symbol() {
+        // the squares mod 11 are 1, 3, 4, 5 and 9
+        let p = U256::from(11);
+        let symbols: Vec<i8> = (0..11u64).map(|a| legendre_symbol(U256::from(a), p).unwrap()).collect();
+        assert_eq!(symbols, vec![0, 1, -1, 1, 1, 1, -1, -1, -1, 1, -1]);
+        assert_eq!(legendre_symbol(U256::from(14), p), Ok(1));
+        assert_eq!(legendre_symbol(U256::one(), U256::zero()), Err(Errors::ZeroModulo));
+        // y^2 of the base point is a square, -1 is not one for p = 3 mod 4
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let y = FieldElement::new(secp256k1.g.1, secp256k1.p);
+        assert_eq!(legendre_symbol(y.square().value(), secp256k1.p), Ok(1));
+        assert_eq!(legendre_symbol(secp256k1.p - U256::one(), secp256k1.p), Ok(-1));
+    }
+}
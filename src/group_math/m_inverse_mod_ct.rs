@@ -0,0 +1,53 @@
+use crate::types::{U256, Errors, FieldElement};
+
+/// Returns the multiplicative inverse of k modulo p as k^(p-2) (Fermat's little theorem).
+/// It is a fixed exponentiation chain: the squarings and multiplications only depend on p,
+/// so unlike m_inverse_mod there is no branching on k in the chain itself. This is not
+/// constant time, the U256/U512 multiplication and reduction still branch.
+/// k must be non-zero and p must be a prime.
+pub fn m_inverse_mod_ct<T: Into<U256>, U: Into<U256>>(k: T, p: U) -> Result<U256, Errors> {
+    let (k, p) = (k.into(), p.into());
+    if p == U256::zero() {
+        return Err(Errors::ZeroModulo);
+    }
+    let element = FieldElement::new(k, p);
+    if element.is_zero() {
+        return Err(Errors::ZeroDivision);
+    }
+    let inverse = element.pow(p.saturating_sub(U256::from(2)));
+    // only fails if p is not a prime
+    if inverse * element != FieldElement::one(p) {
+        return Err(Errors::NoMultiplicativeInverse(k, p));
+    }
+    Ok(inverse.value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EllipticCurve;
+    use crate::group_math::m_inverse_mod;
+
+    #[test]
+    fn test_m_inverse_mod_ct() {
+        // 5 has 9 as multiplicative inverse mod 11
+        let p = U256::from(11);
+        assert_eq!(m_inverse_mod_ct(U256::from(5), p), Ok(U256::from(9)));
+        // finding the multiplicative inverse of 0 is not defined
+        assert_eq!(m_inverse_mod_ct(U256::zero(), p), Err(Errors::ZeroDivision));
+        assert_eq!(m_inverse_mod_ct(p, p), Err(Errors::ZeroDivision));
+        assert_eq!(m_inverse_mod_ct(U256::one(), U256::zero()), Err(Errors::ZeroModulo));
+        // 5 has no multiplicative inverse mod 10
+        let p = U256::from(10);
+        assert_eq!(m_inverse_mod_ct(U256::from(5), p), Err(Errors::NoMultiplicativeInverse(U256::from(5), p)));
+        // same results as the variable time version for both p and n of secp256k1
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let k = U256::from_dec_str("51962848049517897314481377586705320001209492118704192225945377961561169702593").unwrap();
+        let x = U256::from_dec_str("15770621123931935841922866852148091009166141688620356011139719709837462056333").unwrap();
+        assert_eq!(m_inverse_mod_ct(k, secp256k1.p), Ok(x));
+        for k in [U256::one(), U256::from(2), k, secp256k1.n - U256::one()].iter() {
+            assert_eq!(m_inverse_mod_ct(*k, secp256k1.n), m_inverse_mod(*k, secp256k1.n));
+            assert_eq!(m_inverse_mod_ct(*k, secp256k1.p), m_inverse_mod(*k, secp256k1.p));
+        }
+    }
+}
@@ -2,3 +2,17 @@ pub mod a_inverse_mod;
 pub use self::a_inverse_mod::a_inverse_mod;
 pub mod m_inverse_mod;
 pub use self::m_inverse_mod::m_inverse_mod;
+pub mod m_inverse_mod_ct;
+pub use self::m_inverse_mod_ct::m_inverse_mod_ct;
+pub mod decompose_scalar;
+pub use self::decompose_scalar::decompose_scalar;
+pub mod batch_inverse;
+pub use self::batch_inverse::batch_inverse;
+pub mod legendre_symbol;
+pub use self::legendre_symbol::legendre_symbol;
+pub mod sqrt_mod;
+pub use self::sqrt_mod::sqrt_mod;
+pub mod polynomial_evaluate;
+pub use self::polynomial_evaluate::polynomial_evaluate;
+pub mod lagrange_coefficient;
+pub use self::lagrange_coefficient::lagrange_coefficient;
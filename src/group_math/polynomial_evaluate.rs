@@ -0,0 +1,30 @@
+use crate::types::{U256, FieldElement, Errors};
+
+/// Returns f(x) = c0 + c1 * x + ... + ck * x^k modulo p using Horner's method, `coefficients` starts with c0.
+pub fn polynomial_evaluate<T: Into<U256>, U: Into<U256>>(coefficients: &[U256], x: T, p: U) -> Result<U256, Errors> {
+    let (x, p) = (x.into(), p.into());
+    if p == U256::zero() {
+        return Err(Errors::ZeroModulo);
+    }
+    let x = FieldElement::new(x, p);
+    let result = coefficients.iter().rev()
+        .fold(FieldElement::zero(p), |result, coefficient| result * x + FieldElement::new(*coefficient, p));
+    Ok(result.value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polynomial_evaluate() -> Result<(), Errors> {
+        // f(x) = 3 + 2x + x^2 mod 11
+        let coefficients = [U256::from(3), U256::from(2), U256::from(1)];
+        assert_eq!(polynomial_evaluate(&coefficients, 0, 11)?, U256::from(3));
+        assert_eq!(polynomial_evaluate(&coefficients, 2, 11)?, U256::from(0));
+        assert_eq!(polynomial_evaluate(&coefficients, 5, 11)?, U256::from(5));
+        assert_eq!(polynomial_evaluate(&[], 5, 11)?, U256::zero());
+        assert_eq!(polynomial_evaluate(&coefficients, 5, 0), Err(Errors::ZeroModulo));
+        Ok(())
+    }
+}
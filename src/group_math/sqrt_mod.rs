@@ -0,0 +1,94 @@
+use crate::types::{U256, Errors, FieldElement};
+use crate::group_math::legendre_symbol;
+
+/// Returns a square root r of a modulo the prime p, the other root is p - r.
+/// Uses a single exponentiation a^((p+1)/4) when p = 3 mod 4 and Tonelli-Shanks otherwise.
+/// Returns NoSquareRoot if a is not a quadratic residue mod p.
+pub fn sqrt_mod<T: Into<U256>, U: Into<U256>>(a: T, p: U) -> Result<U256, Errors> {
+    let (a, p) = (a.into(), p.into());
+    if p == U256::zero() {
+        return Err(Errors::ZeroModulo);
+    }
+    let a = FieldElement::new(a, p);
+    if a.is_zero() || p == U256::from(2) {
+        return Ok(a.value());
+    }
+    if legendre_symbol(a.value(), p)? != 1 {
+        return Err(Errors::NoSquareRoot(a.value()));
+    }
+    if p.low_u64() & 3 == 3 {
+        // (p + 1) / 4 without overflowing
+        return Ok(a.pow((p >> 2) + U256::one()).value());
+    }
+    // p - 1 = q * 2^s with q odd
+    let mut q = p - U256::one();
+    let mut s = 0;
+    while !q.bit(0) {
+        q >>= 1;
+        s += 1;
+    }
+    // any quadratic non-residue z
+    let mut z = U256::from(2);
+    while legendre_symbol(z, p)? != -1 {
+        z += U256::one();
+        if z >= p {
+            return Err(Errors::NoSquareRoot(a.value()));
+        }
+    }
+    let one = FieldElement::one(p);
+    let mut m = s;
+    let mut c = FieldElement::new(z, p).pow(q);
+    let mut t = a.pow(q);
+    let mut r = a.pow((q >> 1) + U256::one());
+    // invariant: r^2 = a * t, t has order 2^i for some i < m
+    while t != one {
+        let mut i = 0;
+        let mut t_2i = t;
+        while t_2i != one {
+            t_2i = t_2i.square();
+            i += 1;
+        }
+        let mut b = c;
+        for _ in 0..(m - i - 1) {
+            b = b.square();
+        }
+        m = i;
+        c = b.square();
+        t = t * c;
+        r = r * b;
+    }
+    Ok(r.value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EllipticCurve;
+
+    #[test]
+    fn test_sqrt_mod() -> Result<(), Errors> {
+        // 97 = 3 * 2^5 + 1 goes through Tonelli-Shanks, 23 through the p = 3 mod 4 shortcut
+        for p in [U256::from(97), U256::from(23)].iter() {
+            for a in 0..p.low_u64() {
+                let a = U256::from(a);
+                match legendre_symbol(a, *p)? {
+                    -1 => assert_eq!(sqrt_mod(a, *p), Err(Errors::NoSquareRoot(a))),
+                    _ => {
+                        let r = FieldElement::new(sqrt_mod(a, *p)?, *p);
+                        assert_eq!(r.square().value(), a);
+                    }
+                }
+            }
+        }
+        assert_eq!(sqrt_mod(U256::one(), U256::from(2))?, U256::one());
+        assert_eq!(sqrt_mod(U256::one(), U256::zero()), Err(Errors::ZeroModulo));
+        // big primes: p of secp256k1 is 3 mod 4, n is 1 mod 64
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        for p in [secp256k1.p, secp256k1.n].iter() {
+            let x = FieldElement::new(secp256k1.g.0, *p);
+            let root = sqrt_mod(x.square().value(), *p)?;
+            assert!(root == x.value() || root == (-x).value());
+        }
+        Ok(())
+    }
+}
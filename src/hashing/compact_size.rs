@@ -0,0 +1,25 @@
+/// Returns `length` encoded as a Bitcoin compact size integer (1, 3, 5 or 9 bytes).
+pub fn compact_size(length: u64) -> Vec<u8> {
+    let (prefix, bytes) = match length {
+        0..=0xfc => return vec![length as u8],
+        0xfd..=0xffff => (0xfd, 2),
+        0x1_0000..=0xffff_ffff => (0xfe, 4),
+        _ => (0xff, 8)
+    };
+    let mut data = vec![prefix];
+    data.extend_from_slice(&length.to_le_bytes()[..bytes]);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_size() {
+        assert_eq!(compact_size(0xfc), vec![0xfc]);
+        assert_eq!(compact_size(0xfd), vec![0xfd, 0xfd, 0x00]);
+        assert_eq!(compact_size(0x1_0000), vec![0xfe, 0x00, 0x00, 0x01, 0x00]);
+        assert_eq!(compact_size(0x1_0000_0000), vec![0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]);
+    }
+}
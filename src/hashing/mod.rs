@@ -0,0 +1,4 @@
+pub mod tagged_hash;
+pub use self::tagged_hash::tagged_hash;
+pub mod compact_size;
+pub use self::compact_size::compact_size;
@@ -0,0 +1,29 @@
+use ring::digest::{digest, Context, SHA256};
+
+/// Returns the BIP340 tagged hash SHA256(SHA256(tag) || SHA256(tag) || data), the parts of `data` are concatenated.
+pub fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = digest(&SHA256, tag.as_bytes());
+    let mut context = Context::new(&SHA256);
+    context.update(tag_hash.as_ref());
+    context.update(tag_hash.as_ref());
+    for part in data.iter() {
+        context.update(part);
+    }
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(context.finish().as_ref());
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tagged_hash() {
+        let tag = digest(&SHA256, b"BIP0340/challenge");
+        let mut data = tag.as_ref().to_vec();
+        data.extend_from_slice(tag.as_ref());
+        data.extend_from_slice(b"abc");
+        assert_eq!(tagged_hash("BIP0340/challenge", &[b"a", b"bc"]).to_vec(), digest(&SHA256, &data).as_ref().to_vec());
+    }
+}
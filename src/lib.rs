@@ -1,5 +1,6 @@
 #![crate_name="bitcoin_playground"]
 pub mod types;
+pub mod hashing;
 pub mod group_math;
 pub mod ec_math;
 pub mod utils;
@@ -1,7 +1,7 @@
-use bitcoin_playground::types::{Points, EllipticCurve};
+use bitcoin_playground::types::{Points, EllipticCurve, MessageDigest};
 use bitcoin_playground::types::Errors;
-use bitcoin_playground::ec_math::scalar_mult;
-use bitcoin_playground::utils::{make_keypair, sign_message, verify_signature};
+use bitcoin_playground::ec_math::scalar_mult_ladder;
+use bitcoin_playground::utils::{make_keypair, sign_message, sign_message_with_rng, verify_signature};
 
 fn main() -> Result<(), Errors> {
     let secp256k1 = EllipticCurve::secp256k1_factory();
@@ -15,18 +15,18 @@ fn main() -> Result<(), Errors> {
     let (b, B) = make_keypair(&secp256k1)?;
     println!("Alice priv key(a): {:#x}", a);
     println!("Alice pub key(A): {:#x}", A);
-    println!("");
+    println!();
     println!("Bob priv key(b): {:#x}", b);
     println!("Bob pub key(B): {:#x}", B);
-    println!("");
+    println!();
     println!("Shared key for Alice => a * B");
     #[allow(non_snake_case)]
     let B: Points = B.into();
-    println!("==> {:#x}", scalar_mult(a, &B, &secp256k1)?);
+    println!("==> {:#x}", scalar_mult_ladder(a.value(), &B, &secp256k1)?);
     println!("Shared key for Bob => b * A");
     #[allow(non_snake_case)]
     let A: Points = A.into();
-    println!("==> {:#x}", scalar_mult(b, &A, &secp256k1)?);
+    println!("==> {:#x}", scalar_mult_ladder(b.value(), &A, &secp256k1)?);
 
     // ECDSA
     println!("\nECDSA\n");
@@ -34,38 +34,38 @@ fn main() -> Result<(), Errors> {
     let (a, A) = make_keypair(&secp256k1)?;
     println!("Private key(Alice): {:#x}", a);
     println!("Public key(Alice): {:#x}", A);
-    println!("");
+    println!();
 
-    let mut rng = rand::thread_rng();
-    let signature = sign_message(&mut rng, a, b"Hello", &secp256k1)?;
+    let signature = sign_message(a, b"Hello", MessageDigest::Sha256, &secp256k1)?;
     println!("Scenario: Alice signs the message (sig should be ok).");
-    println!("Message: '{}'", "Hello");
+    println!("Message: 'Hello'");
     println!("Signature: {:#x}", signature);
-    let ver = verify_signature(A, b"Hello", &signature, &secp256k1)?;
+    let ver = verify_signature(A, b"Hello", &signature, MessageDigest::Sha256, &secp256k1)?;
     println!("Verification: {:?}", ver);
-    println!("");
+    println!();
 
-    let second_signature = sign_message(&mut rng, a, b"Hello", &secp256k1)?;
-    println!("Scenario: Alice signs the message[again] (sig should be ok, but it is a different pair (r, s)).");
-    println!("Message: '{}'", "Hello");
+    let mut rng = rand::thread_rng();
+    let second_signature = sign_message_with_rng(&mut rng, a, b"Hello", MessageDigest::Sha256, &secp256k1)?;
+    println!("Scenario: Alice signs the message[again] with a random nonce (sig should be ok, but it is a different pair (r, s)).");
+    println!("Message: 'Hello'");
     println!("Signature: {:#x}", second_signature);
-    let ver = verify_signature(A, b"Hello", &second_signature, &secp256k1)?;
+    let ver = verify_signature(A, b"Hello", &second_signature, MessageDigest::Sha256, &secp256k1)?;
     println!("Verification: {:?}", ver);
-    println!("");
+    println!();
 
     println!("Scenario: A different message is supplied to verification function (sig should fail[different hash of message]).");
-    println!("Message: '{}'", "Hellow world!");
+    println!("Message: 'Hellow world!'");
     println!("Signature: {:#x}", signature);
-    let ver = verify_signature(A, b"Hello world!", &signature, &secp256k1)?;
+    let ver = verify_signature(A, b"Hello world!", &signature, MessageDigest::Sha256, &secp256k1)?;
     println!("Verification: {:?}", ver);
-    println!("");
+    println!();
 
     println!("Scenario: Bob (not Alice) signs the message (but we are using Alice's pub key to verify, so it will fail).");
-    println!("Message: '{}'", "Hello");
+    println!("Message: 'Hello'");
     let (b, _) = make_keypair(&secp256k1)?;
-    let other_sig = sign_message(&mut rng, b, b"Hello", &secp256k1)?;
+    let other_sig = sign_message(b, b"Hello", MessageDigest::Sha256, &secp256k1)?;
     println!("Signature: {:#x}", other_sig);
-    let ver = verify_signature(A, b"Hello", &other_sig, &secp256k1)?;
+    let ver = verify_signature(A, b"Hello", &other_sig, MessageDigest::Sha256, &secp256k1)?;
     println!("Verification: {:?}", ver);
 
     Ok(())
@@ -0,0 +1,70 @@
+use crate::types::{XOnlyPublicKey, EllipticCurve, Errors};
+
+/// Represents the BIP341 control block of a script path spend: the leaf version, the parity of the
+/// output key, the internal key and the hashes of the siblings on the path from the leaf to the root.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ControlBlock {
+    pub leaf_version: u8,
+    pub output_key_parity: bool,
+    pub internal_key: XOnlyPublicKey,
+    pub merkle_path: Vec<[u8; 32]>
+}
+
+impl ControlBlock {
+    /// Most hashes a merkle path can hold.
+    pub const MAX_PATH_LENGTH: usize = 128;
+
+    /// Returns the encoding (leaf version | parity) || internal key || merkle path, 33 + 32m bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.leaf_version | self.output_key_parity as u8];
+        bytes.extend_from_slice(&self.internal_key.to_bytes());
+        for hash in self.merkle_path.iter() {
+            bytes.extend_from_slice(hash);
+        }
+        bytes
+    }
+    /// Parses the encoding, the length must be 33 + 32m with m at most 128 and the internal key valid.
+    pub fn from_bytes(bytes: &[u8], curve: &EllipticCurve) -> Result<Self, Errors> {
+        if bytes.len() < 33 || !(bytes.len() - 33).is_multiple_of(32) || (bytes.len() - 33) / 32 > ControlBlock::MAX_PATH_LENGTH {
+            return Err(Errors::InvalidControlBlockLength(bytes.len()));
+        }
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&bytes[1..33]);
+        let merkle_path = bytes[33..].chunks(32).map(|chunk| {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(chunk);
+            hash
+        }).collect();
+        Ok(ControlBlock {
+            leaf_version: bytes[0] & 0xfe,
+            output_key_parity: bytes[0] & 1 == 1,
+            internal_key: XOnlyPublicKey::from_bytes(&key, curve)?,
+            merkle_path
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+
+    #[test]
+    fn test_control_block() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // control block of the first leaf of BIP341 scriptPubKey vector 3
+        let bytes = hex::decode("c1ee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf3786592f224a923cd0021ab202ab139cc56802ddb92dcfc172b9212261a539df79a112a").unwrap();
+        let control_block = ControlBlock::from_bytes(&bytes, &secp256k1)?;
+        assert_eq!(control_block.leaf_version, 0xc0);
+        assert!(control_block.output_key_parity);
+        assert_eq!(control_block.internal_key.x(), "ee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf3786592".parse::<U256>().unwrap());
+        assert_eq!(control_block.merkle_path.len(), 1);
+        assert_eq!(control_block.to_bytes(), bytes);
+        assert_eq!(ControlBlock::from_bytes(&bytes[..32], &secp256k1), Err(Errors::InvalidControlBlockLength(32)));
+        assert_eq!(ControlBlock::from_bytes(&bytes[..64], &secp256k1), Err(Errors::InvalidControlBlockLength(64)));
+        let mut long = bytes[..33].to_vec();
+        long.extend(vec![0u8; 32 * 129]);
+        assert_eq!(ControlBlock::from_bytes(&long, &secp256k1), Err(Errors::InvalidControlBlockLength(33 + 32 * 129)));
+        Ok(())
+    }
+}
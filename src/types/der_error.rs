@@ -0,0 +1,28 @@
+#[derive(Debug,PartialEq,Clone,Copy)]
+/// Reasons a DER encoded signature is rejected, offsets point at the tag byte of the integer (r or s).
+pub enum DerError {
+    /// Shorter than the smallest possible signature (8 bytes)
+    TooShort(usize),
+    /// Longer than the largest possible signature (72 bytes)
+    TooLong(usize),
+    /// The input ended in the middle of an element
+    UnexpectedEnd,
+    /// The first byte is not the 0x30 sequence tag
+    NotASequence(u8),
+    /// The sequence length (first value) does not cover the rest of the input (second value)
+    SequenceLengthMismatch(usize, usize),
+    /// The lengths of r and s do not add up to the sequence length
+    IntegerLengthMismatch,
+    /// A length uses the long form or is otherwise malformed
+    InvalidLength(usize),
+    /// The element is not tagged as an integer (0x02)
+    NotAnInteger(usize),
+    /// The integer has no bytes
+    ZeroLengthInteger(usize),
+    /// The integer has its sign bit set
+    NegativeInteger(usize),
+    /// The integer starts with a needless zero byte
+    ExcessPadding(usize),
+    /// The integer does not fit into 256 bits
+    IntegerTooLarge(usize),
+}
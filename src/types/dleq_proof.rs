@@ -0,0 +1,30 @@
+use crate::types::{Scalar, EllipticCurve, Errors};
+
+/// Represents a proof that two points A = x * G and B = x * H share the discrete logarithm x
+/// (Chaum-Pedersen): the challenge e and the response s = q + e * x.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DleqProof {
+    pub e: Scalar,
+    pub s: Scalar
+}
+
+impl DleqProof {
+    /// Creates instance of DleqProof from e, s.
+    pub fn new(e: Scalar, s: Scalar) -> Self {
+        DleqProof {e, s}
+    }
+    /// Returns the 64 byte encoding e || s.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.e.to_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+    /// Parses the 64 byte encoding, e and s must be below n.
+    pub fn from_bytes(bytes: &[u8; 64], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let (mut e, mut s) = ([0u8; 32], [0u8; 32]);
+        e.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..]);
+        Ok(DleqProof::new(Scalar::from_bytes(&e, curve)?, Scalar::from_bytes(&s, curve)?))
+    }
+}
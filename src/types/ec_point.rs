@@ -1,4 +1,5 @@
-use crate::types::Point;
+use crate::types::{U256, Point, EllipticCurve, Errors};
+use crate::ec_math::{check_if_on_curve, lift_x};
 
 #[derive(Debug,PartialEq,Clone,Copy)]
 /// Represents a point on curve
@@ -24,4 +25,124 @@ impl ECpoint {
             ECpoint::OnCurve(p) => p
         }
     }
+    /// Returns the SEC1 encoding: 02/03 || x if `compressed` (03 for odd y), 04 || x || y otherwise.
+    /// Coordinates take as many bytes as p does (32 for secp256k1), Infinity is the single byte 00.
+    pub fn to_sec1(&self, compressed: bool, curve: &EllipticCurve) -> Vec<u8> {
+        match (self, compressed) {
+            (ECpoint::Infinity, _) => vec![0x00],
+            (ECpoint::OnCurve(p), true) => Self::sec1_bytes(0x02 + p.y.bit(0) as u8, &[p.x], curve),
+            (ECpoint::OnCurve(p), false) => Self::sec1_bytes(0x04, &[p.x, p.y], curve)
+        }
+    }
+    /// Returns the SEC1 hybrid encoding 06/07 || x || y (07 for odd y).
+    pub fn to_sec1_hybrid(&self, curve: &EllipticCurve) -> Vec<u8> {
+        match self {
+            ECpoint::Infinity => vec![0x00],
+            ECpoint::OnCurve(p) => Self::sec1_bytes(0x06 + p.y.bit(0) as u8, &[p.x, p.y], curve)
+        }
+    }
+    /// Parses any SEC1 encoding (compressed, uncompressed, hybrid or 00 for Infinity).
+    /// Coordinates must be below p and the point must lie on the curve.
+    pub fn from_sec1(bytes: &[u8], curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+        let length = Self::field_length(curve);
+        let prefix = match bytes.first() {
+            Some(prefix) => *prefix,
+            None => return Err(Errors::InvalidSec1Length(0))
+        };
+        let expected_length = match prefix {
+            0x00 => 1,
+            0x02 | 0x03 => 1 + length,
+            0x04 | 0x06 | 0x07 => 1 + 2 * length,
+            _ => return Err(Errors::InvalidSec1Prefix(prefix))
+        };
+        if bytes.len() != expected_length {
+            return Err(Errors::InvalidSec1Length(bytes.len()));
+        }
+        if prefix == 0x00 {
+            return Ok(ECpoint::Infinity);
+        }
+        let x = U256::from_big_endian(&bytes[1..1 + length]);
+        if prefix == 0x02 || prefix == 0x03 {
+            return lift_x(x, prefix == 0x03, curve);
+        }
+        let point = Point::new(x, U256::from_big_endian(&bytes[1 + length..]));
+        if point.x >= curve.p || point.y >= curve.p {
+            return Err(Errors::PointNotOnCurve(point));
+        }
+        if prefix != 0x04 && point.y.bit(0) != (prefix == 0x07) {
+            return Err(Errors::Sec1HybridParityMismatch(prefix));
+        }
+        check_if_on_curve(point, curve)
+    }
+    /// Number of bytes of a coordinate, the byte length of p.
+    fn field_length(curve: &EllipticCurve) -> usize {
+        curve.p.bits().div_ceil(8)
+    }
+    fn sec1_bytes(prefix: u8, coordinates: &[U256], curve: &EllipticCurve) -> Vec<u8> {
+        let length = Self::field_length(curve);
+        let mut bytes = vec![prefix];
+        for coordinate in coordinates.iter() {
+            let mut buffer = [0u8; 32];
+            coordinate.to_big_endian(&mut buffer);
+            bytes.extend_from_slice(&buffer[32 - length..]);
+        }
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ec_math::point_neg;
+
+    #[test]
+    fn test_sec1() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let G = ECpoint::OnCurve(Point::from(secp256k1.g));
+        let x = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let y = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        assert_eq!(hex::encode(G.to_sec1(true, &secp256k1)), format!("02{}", x));
+        assert_eq!(hex::encode(G.to_sec1(false, &secp256k1)), format!("04{}{}", x, y));
+        assert_eq!(hex::encode(G.to_sec1_hybrid(&secp256k1)), format!("06{}{}", x, y));
+        assert_eq!(ECpoint::Infinity.to_sec1(true, &secp256k1), vec![0x00]);
+        // every encoding round trips, also for odd y
+        let minus_g = point_neg(&G.into(), &secp256k1)?;
+        for point in [G, minus_g, ECpoint::Infinity].iter() {
+            assert_eq!(ECpoint::from_sec1(&point.to_sec1(true, &secp256k1), &secp256k1)?, *point);
+            assert_eq!(ECpoint::from_sec1(&point.to_sec1(false, &secp256k1), &secp256k1)?, *point);
+            assert_eq!(ECpoint::from_sec1(&point.to_sec1_hybrid(&secp256k1), &secp256k1)?, *point);
+        }
+        assert_eq!(minus_g.to_sec1(true, &secp256k1)[0], 0x03);
+        assert_eq!(minus_g.to_sec1_hybrid(&secp256k1)[0], 0x07);
+        // bad prefixes and lengths
+        let compressed = G.to_sec1(true, &secp256k1);
+        let uncompressed = G.to_sec1(false, &secp256k1);
+        assert_eq!(ECpoint::from_sec1(&[], &secp256k1), Err(Errors::InvalidSec1Length(0)));
+        assert_eq!(ECpoint::from_sec1(&[0x05; 33], &secp256k1), Err(Errors::InvalidSec1Prefix(0x05)));
+        assert_eq!(ECpoint::from_sec1(&compressed[..32], &secp256k1), Err(Errors::InvalidSec1Length(32)));
+        assert_eq!(ECpoint::from_sec1(&uncompressed[..33], &secp256k1), Err(Errors::InvalidSec1Length(33)));
+        assert_eq!(ECpoint::from_sec1(&[0x00, 0x00], &secp256k1), Err(Errors::InvalidSec1Length(2)));
+        // hybrid prefix must match the parity of y
+        let mut hybrid = G.to_sec1_hybrid(&secp256k1);
+        hybrid[0] = 0x07;
+        assert_eq!(ECpoint::from_sec1(&hybrid, &secp256k1), Err(Errors::Sec1HybridParityMismatch(0x07)));
+        // off curve points and coordinates not below p
+        let mut off_curve = uncompressed.clone();
+        off_curve[64] ^= 1;
+        let point = Point::new(secp256k1.g.0, secp256k1.g.1 ^ U256::one());
+        assert_eq!(ECpoint::from_sec1(&off_curve, &secp256k1), Err(Errors::PointNotOnCurve(point)));
+        let mut zero_x = compressed;
+        zero_x[1..].copy_from_slice(&[0u8; 32]);
+        assert_eq!(ECpoint::from_sec1(&zero_x, &secp256k1), Err(Errors::XNotOnCurve(U256::zero())));
+        // a small curve uses single byte coordinates
+        let mut tutorial = EllipticCurve::new("tutorial");
+        tutorial.set_p("97").set_a(2).set_b(3).set_g(("3", "6")).set_n("5").set_h("20");
+        let g = ECpoint::OnCurve(Point::from(tutorial.g));
+        assert_eq!(g.to_sec1(true, &tutorial), vec![0x02, 3]);
+        assert_eq!(g.to_sec1(false, &tutorial), vec![0x04, 3, 6]);
+        assert_eq!(ECpoint::from_sec1(&[0x03, 3], &tutorial)?, ECpoint::OnCurve(Point::new(U256::from(3), U256::from(91))));
+        assert_eq!(ECpoint::from_sec1(&[0x04, 100, 6], &tutorial), Err(Errors::PointNotOnCurve(Point::new(U256::from(100), U256::from(6)))));
+        Ok(())
+    }
 }
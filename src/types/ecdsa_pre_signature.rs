@@ -0,0 +1,74 @@
+use crate::types::{Scalar, ECpoint, DleqProof, EllipticCurve, Errors};
+
+/// Represents an ECDSA adaptor (pre) signature for an adaptor point T: the final nonce point R = k * T,
+/// R_a = k * G, s' = k^-1 * (z + r * d) with r = x(R) and a proof that R and R_a share k.
+/// Multiplying s' by t^-1 gives the ECDSA signature (r, s), seeing both reveals t.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EcdsaPreSignature {
+    pub r: ECpoint,
+    pub r_a: ECpoint,
+    pub s: Scalar,
+    pub proof: DleqProof
+}
+
+impl EcdsaPreSignature {
+    /// Returns the 162 byte encoding: R and R_a compressed, s' and the proof.
+    pub fn to_bytes(&self, curve: &EllipticCurve) -> Result<[u8; 162], Errors> {
+        if self.r == ECpoint::Infinity || self.r_a == ECpoint::Infinity {
+            return Err(Errors::PointAtInfinity);
+        }
+        let mut bytes = [0u8; 162];
+        bytes[..33].copy_from_slice(&self.r.to_sec1(true, curve));
+        bytes[33..66].copy_from_slice(&self.r_a.to_sec1(true, curve));
+        bytes[66..98].copy_from_slice(&self.s.to_bytes());
+        bytes[98..].copy_from_slice(&self.proof.to_bytes());
+        Ok(bytes)
+    }
+    /// Parses the 162 byte encoding, the points must be compressed and the scalars below n.
+    pub fn from_bytes(bytes: &[u8; 162], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let point = |bytes: &[u8]| match bytes[0] {
+            0x02 | 0x03 => ECpoint::from_sec1(bytes, curve),
+            prefix => Err(Errors::InvalidSec1Prefix(prefix))
+        };
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&bytes[66..98]);
+        let mut proof = [0u8; 64];
+        proof.copy_from_slice(&bytes[98..]);
+        Ok(EcdsaPreSignature {
+            r: point(&bytes[..33])?,
+            r_a: point(&bytes[33..66])?,
+            s: Scalar::from_bytes(&s, curve)?,
+            proof: DleqProof::from_bytes(&proof, curve)?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::ec_math::scalar_mult_base;
+
+    #[test]
+    fn test_ecdsa_pre_signature() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let scalar = |v: u64| Scalar::new(U256::from(v), &secp256k1);
+        let pre_signature = EcdsaPreSignature {
+            r: scalar_mult_base(U256::from(2), &secp256k1)?,
+            r_a: scalar_mult_base(U256::from(3), &secp256k1)?,
+            s: scalar(4),
+            proof: DleqProof::new(scalar(5), scalar(6))
+        };
+        let bytes = pre_signature.to_bytes(&secp256k1)?;
+        assert_eq!(EcdsaPreSignature::from_bytes(&bytes, &secp256k1)?, pre_signature);
+        let mut bad = bytes;
+        bad[33] = 0x04;
+        assert_eq!(EcdsaPreSignature::from_bytes(&bad, &secp256k1), Err(Errors::InvalidSec1Prefix(0x04)));
+        let mut bad = bytes;
+        bad[98..130].copy_from_slice(&[0xff; 32]);
+        assert!(matches!(EcdsaPreSignature::from_bytes(&bad, &secp256k1), Err(Errors::ScalarOutOfRange(_))));
+        let infinite = EcdsaPreSignature {r_a: ECpoint::Infinity, ..pre_signature};
+        assert_eq!(infinite.to_bytes(&secp256k1), Err(Errors::PointAtInfinity));
+        Ok(())
+    }
+}
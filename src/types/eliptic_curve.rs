@@ -1,6 +1,6 @@
-use crate::types::U256;
+use crate::types::{U256, GeneratorTable, Endomorphism};
+use std::sync::{Arc, Mutex};
 
-#[derive(Debug)]
 /// Holds elliptic curve parameters
 pub struct EllipticCurve {
     ///name of the curve
@@ -16,7 +16,11 @@ pub struct EllipticCurve {
     ///subgroup order
     pub n: U256,
     ///subgroup cofactor
-    pub h: U256
+    pub h: U256,
+    ///endomorphism used to speed up scalar multiplication, if the curve has one
+    pub endomorphism: Option<Endomorphism>,
+    ///multiples of the base point, built on first use
+    generator_table: Mutex<Option<Arc<GeneratorTable>>>
 }
 
 
@@ -24,12 +28,12 @@ impl EllipticCurve {
     /// if a str is given with 0x it will treat it as hexa string otherwise a decadic number
     fn pick_hex_or_dec(s: &str) -> U256{
         match s.starts_with("0x"){
-            true => U256::from_big_endian(&hex::decode(s.trim_start_matches("0x")).unwrap()),
+            true => s.trim_start_matches("0x").parse().unwrap(),
             false => U256::from_dec_str(s).unwrap()
         }
     }
-    /// Creates a new instance filled with defaults
-    fn new(name: &str) -> Self {
+    /// Creates a new instance filled with defaults, the parameters are filled in by the setters.
+    pub fn new(name: &str) -> Self {
         Self {
             name: name.into(),
             p: U256::default(),
@@ -39,32 +43,52 @@ impl EllipticCurve {
                 U256::default()),
             n: U256::default(),
             h: U256::default(),
+            endomorphism: None,
+            generator_table: Mutex::new(None),
         }
     }
-    fn set_p(&mut self, p: &str) -> &mut Self{
+    /// Sets the prime p, hex with 0x prefix or decimal
+    pub fn set_p(&mut self, p: &str) -> &mut Self{
         self.p = Self::pick_hex_or_dec(p);
         self
     }
-    fn set_a(&mut self, a: i64) -> &mut Self{
+    /// Sets the a coefficient
+    pub fn set_a(&mut self, a: i64) -> &mut Self{
         self.a = a;
         self
     }
-    fn set_b(&mut self, b: i64) -> &mut Self{
+    /// Sets the b coefficient
+    pub fn set_b(&mut self, b: i64) -> &mut Self{
         self.b = b;
         self
     }
-    fn set_g(&mut self, g: (&str, &str)) -> &mut Self{
+    /// Sets the base point, hex with 0x prefix or decimal
+    pub fn set_g(&mut self, g: (&str, &str)) -> &mut Self{
         self.g = (Self::pick_hex_or_dec(g.0), Self::pick_hex_or_dec(g.1));
         self
     }
-    fn set_n(&mut self, n: &str) -> &mut Self{
+    /// Sets the subgroup order n, hex with 0x prefix or decimal
+    pub fn set_n(&mut self, n: &str) -> &mut Self{
         self.n = Self::pick_hex_or_dec(n);
         self
     }
-    fn set_h(&mut self, h: &str) -> &mut Self{
+    /// Sets the cofactor h, hex with 0x prefix or decimal
+    pub fn set_h(&mut self, h: &str) -> &mut Self{
         self.h = Self::pick_hex_or_dec(h);
         self
     }
+    /// basis is ((a1, -b1), (a2, b2))
+    fn set_endomorphism(&mut self, beta: &str, lambda: &str, basis: ((&str, &str), (&str, &str))) -> &mut Self{
+        self.endomorphism = Some(Endomorphism {
+            beta: Self::pick_hex_or_dec(beta),
+            lambda: Self::pick_hex_or_dec(lambda),
+            a1: Self::pick_hex_or_dec((basis.0).0),
+            minus_b1: Self::pick_hex_or_dec((basis.0).1),
+            a2: Self::pick_hex_or_dec((basis.1).0),
+            b2: Self::pick_hex_or_dec((basis.1).1),
+        });
+        self
+    }
     /// Constructs secp256k1 EllipticCurve
     pub fn secp256k1_factory() -> EllipticCurve {
         let mut secp256k1 = EllipticCurve::new("secp256k1");
@@ -79,14 +103,75 @@ impl EllipticCurve {
         // Subgroup order.
         .set_n("0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
         // Subgroup cofactor.
-        .set_h("1");
+        .set_h("1")
+        // Endomorphism (beta * x, y) = lambda * (x, y) and the lattice basis to split scalars.
+        .set_endomorphism("0x7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+        "0x5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72",
+        (("0x3086d221a7d46bcde86c90e49284eb15", "0xe4437ed6010e88286f547fa90abfe4c3"),
+        ("0x114ca50f7a8e2f3f657c1108d9d44cfd8", "0x3086d221a7d46bcde86c90e49284eb15")));
         secp256k1
     }
 }
 
+impl EllipticCurve {
+    /// Returns the precomputed multiples of the base point, the table is built on the first call.
+    /// It is rebuilt if p, a, g or n changed since, by a setter or by assigning the field.
+    pub fn generator_table(&self) -> Arc<GeneratorTable> {
+        let mut cached = self.generator_table.lock().unwrap();
+        match cached.as_ref() {
+            Some(table) if table.is_for(self) => Arc::clone(table),
+            _ => {
+                let table = Arc::new(GeneratorTable::new(self));
+                *cached = Some(Arc::clone(&table));
+                table
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for EllipticCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        // the generator table is a cache, not a curve parameter
+        f.debug_struct("EllipticCurve")
+            .field("name", &self.name)
+            .field("p", &self.p)
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("g", &self.g)
+            .field("n", &self.n)
+            .field("h", &self.h)
+            .field("endomorphism", &self.endomorphism)
+            .finish()
+    }
+}
+
 impl std::fmt::Display for EllipticCurve {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         writeln!(f, "{:#?}", self)?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Errors;
+    use crate::ec_math::scalar_mult_base;
+
+    #[test]
+    fn test_elliptic_curve() -> Result<(), Errors> {
+        let mut secp256k1 = EllipticCurve::secp256k1_factory();
+        let two_g = scalar_mult_base(U256::from(2), &secp256k1)?;
+        // the table built for the old base point must not outlive it
+        let point = two_g.to_finite_point();
+        secp256k1.set_g((&format!("{:#x}", point.x), &format!("{:#x}", point.y)));
+        assert_eq!(scalar_mult_base(U256::one(), &secp256k1)?, two_g);
+        assert_ne!(scalar_mult_base(U256::from(2), &secp256k1)?, two_g);
+        // the fields are public, assigning one directly must not leave a stale table either
+        let mut secp256k1 = EllipticCurve::secp256k1_factory();
+        scalar_mult_base(U256::one(), &secp256k1)?;
+        secp256k1.g = (point.x, point.y);
+        assert_eq!(scalar_mult_base(U256::one(), &secp256k1)?, two_g);
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::types::{U256, FieldElement, Point, ECpoint};
+
+#[derive(Debug,PartialEq,Clone,Copy)]
+/// Holds the parameters of an efficiently computable endomorphism
+/// phi(x, y) = (beta * x, y) = lambda * (x, y), together with the short lattice basis
+/// (a1, b1), (a2, b2) used to split a scalar into two halves (GLV method).
+pub struct Endomorphism {
+    ///cube root of unity mod p
+    pub beta: U256,
+    ///cube root of unity mod n
+    pub lambda: U256,
+    ///first basis vector (a1, b1), b1 is negative so -b1 is stored
+    pub a1: U256,
+    pub minus_b1: U256,
+    ///second basis vector (a2, b2)
+    pub a2: U256,
+    pub b2: U256
+}
+
+impl Endomorphism {
+    /// Returns phi(point) = (beta * x, y) for a curve with prime `p`.
+    pub fn map_point(&self, point: &ECpoint, p: U256) -> ECpoint {
+        match point {
+            ECpoint::Infinity => ECpoint::Infinity,
+            ECpoint::OnCurve(point) => {
+                let x = FieldElement::new(self.beta, p) * FieldElement::new(point.x, p);
+                ECpoint::OnCurve(Point::new(x.value(), point.y))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EllipticCurve, Points, Errors};
+    use crate::ec_math::scalar_mult_ladder;
+
+    #[test]
+    fn test_endomorphism() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let endomorphism = secp256k1.endomorphism.unwrap();
+        let g = ECpoint::OnCurve(Point::from(secp256k1.g));
+        // phi(G) = lambda * G
+        assert_eq!(endomorphism.map_point(&g, secp256k1.p), scalar_mult_ladder(endomorphism.lambda, &Points::from(g), &secp256k1)?);
+        assert_eq!(endomorphism.map_point(&ECpoint::Infinity, secp256k1.p), ECpoint::Infinity);
+        // beta and lambda are cube roots of unity
+        assert_eq!(FieldElement::new(endomorphism.beta, secp256k1.p).pow(3.into()), FieldElement::one(secp256k1.p));
+        assert_eq!(FieldElement::new(endomorphism.lambda, secp256k1.n).pow(3.into()), FieldElement::one(secp256k1.n));
+        // a + b * lambda = 0 (mod n) for both basis vectors
+        let lambda = FieldElement::new(endomorphism.lambda, secp256k1.n);
+        let a1 = FieldElement::new(endomorphism.a1, secp256k1.n);
+        let b1 = -FieldElement::new(endomorphism.minus_b1, secp256k1.n);
+        let a2 = FieldElement::new(endomorphism.a2, secp256k1.n);
+        let b2 = FieldElement::new(endomorphism.b2, secp256k1.n);
+        assert!((a1 + b1 * lambda).is_zero());
+        assert!((a2 + b2 * lambda).is_zero());
+        Ok(())
+    }
+}
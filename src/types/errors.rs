@@ -1,4 +1,4 @@
-use crate::types::{U256, Point};
+use crate::types::{U256, Point, DerError};
 
 #[derive(Debug,PartialEq)]
 /// Represents errors
@@ -13,4 +13,77 @@ pub enum Errors {
     PointNotOnCurve(Point),
     /// Negative point not on curve(only happens if point_neg is buggy)
     NegativePointNotOnCurve(Point),
+    /// The value is not below the subgroup order n
+    ScalarOutOfRange(U256),
+    /// The window width is not supported
+    InvalidWindowWidth(usize),
+    /// The curve has no endomorphism parameters
+    NoEndomorphism,
+    /// The number is not a quadratic residue mod p
+    NoSquareRoot(U256),
+    /// No point of the curve has this x coordinate (and parity)
+    XNotOnCurve(U256),
+    /// The SEC1 encoding starts with an unknown prefix byte
+    InvalidSec1Prefix(u8),
+    /// The SEC1 encoding has the wrong length for its prefix
+    InvalidSec1Length(usize),
+    /// The hybrid SEC1 prefix (06/07) does not match the parity of y
+    Sec1HybridParityMismatch(u8),
+    /// The DER encoded signature is malformed
+    InvalidDer(DerError),
+    /// A prehashed message must be 32 bytes long
+    InvalidPrehashLength(usize),
+    /// The recovery id is above 3 or names an x coordinate that is not below p
+    InvalidRecoveryId(u8),
+    /// r or s is outside [1, n-1] or no public key belongs to the signature
+    InvalidSignature,
+    /// A compact signature must be 65 bytes long
+    InvalidCompactLength(usize),
+    /// The header byte of a compact signature is not in [27, 34]
+    InvalidCompactHeader(u8),
+    /// The text is not valid Base64
+    InvalidBase64,
+    /// The point at infinity can not be used here (e.g. as a public key)
+    PointAtInfinity,
+    /// The private key is zero
+    ZeroPrivateKey,
+    /// The derived nonce is zero
+    ZeroNonce,
+    /// Taproot leaf versions must be even
+    InvalidLeafVersion(u8),
+    /// A script tree needs at least one leaf
+    EmptyTapTree,
+    /// The script tree is deeper than a control block can describe (128)
+    TapTreeTooDeep(usize),
+    /// A control block must be 33 + 32m bytes long with m at most 128
+    InvalidControlBlockLength(usize),
+    /// The public key was not part of the key aggregation
+    PublicKeyNotInList,
+    /// The secret nonce belongs to another public key than the private key
+    SecretNonceKeyMismatch,
+    /// The secret nonce is zero (an erased or already used nonce)
+    InvalidSecretNonce,
+    /// The point is not one of the points to interpolate from
+    NotInInterpolationSet(U256),
+    /// The expanded message is longer than 255 hash blocks or the domain separation tag longer than 255 bytes
+    InvalidExpandLength(usize),
+    /// The threshold (first value) must be at least 2 and at most the number of participants (second value)
+    InvalidThreshold(usize, usize),
+    /// The participant with this identifier does not take part
+    ParticipantNotInList(U256),
+    /// The participant with this identifier appears more than once
+    DuplicateParticipant(U256),
+    /// The proof of knowledge of the participant with this identifier is invalid
+    InvalidProofOfKnowledge(U256),
+    /// The secret share sent by the participant with this identifier does not match its commitments
+    InvalidSecretShare(U256),
+    /// A participant identifier must not be zero, the share for zero would be the group secret
+    ZeroIdentifier,
+    /// The number of signature shares (first value) differs from the number of signers (second value)
+    SignatureShareCountMismatch(usize, usize),
+    /// The participant with this identifier committed to a polynomial with a number of coefficients (first value)
+    /// other than the threshold (second value)
+    InvalidCommitmentCount(U256, usize, usize),
+    /// The signature was not completed from the pre-signature with the secret of the adaptor point
+    AdaptorMismatch,
 }
@@ -0,0 +1,182 @@
+use crate::types::{U256, U512, Errors};
+use crate::group_math::{m_inverse_mod, m_inverse_mod_ct};
+use std::ops::{Add, Sub, Mul, Neg};
+
+#[derive(Debug,PartialEq,Eq,Clone,Copy)]
+/// Represents an element of the field of integers modulo `modulus`.
+/// The value is always kept reduced, so it lies in [0, modulus).
+pub struct FieldElement {
+    value: U256,
+    modulus: U256
+}
+
+impl FieldElement {
+    /// Creates a field element from `value` reduced modulo `modulus`.
+    /// Panics if `modulus` is zero.
+    pub fn new(value: U256, modulus: U256) -> Self {
+        if modulus == U256::zero() {
+            panic!("FieldElement::new() can not use a zero modulus!");
+        }
+        Self { value: value % modulus, modulus }
+    }
+    /// Creates a field element from a signed integer (e.g. a curve coefficient).
+    pub fn from_i64(value: i64, modulus: U256) -> Self {
+        let element = Self::new(U256::from(value.unsigned_abs()), modulus);
+        match value < 0 {
+            true => -element,
+            false => element
+        }
+    }
+    /// Returns the additive identity modulo `modulus`.
+    pub fn zero(modulus: U256) -> Self {
+        Self::new(U256::zero(), modulus)
+    }
+    /// Returns the multiplicative identity modulo `modulus`.
+    pub fn one(modulus: U256) -> Self {
+        Self::new(U256::one(), modulus)
+    }
+    /// Returns the reduced value.
+    pub fn value(&self) -> U256 {
+        self.value
+    }
+    /// Returns the modulus the element is bound to.
+    pub fn modulus(&self) -> U256 {
+        self.modulus
+    }
+    /// Returns true for the additive identity.
+    pub fn is_zero(&self) -> bool {
+        self.value == U256::zero()
+    }
+    /// Returns self * self.
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+    /// Returns self ^ exp computed by square and multiply.
+    pub fn pow(&self, exp: U256) -> Self {
+        let mut result = Self::one(self.modulus);
+        for i in (0..exp.bits()).rev() {
+            result = result.square();
+            if exp.bit(i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+    /// Returns the multiplicative inverse by a fixed exponentiation chain (no secret dependent branching
+    /// in the chain itself, the multiplications still branch), the modulus must be a prime.
+    pub fn inverse(&self) -> Result<Self, Errors> {
+        let inverse = m_inverse_mod_ct(self.value, self.modulus)?;
+        Ok(Self { value: inverse, modulus: self.modulus })
+    }
+    /// Returns the multiplicative inverse with the faster variable time algorithm, only for public values.
+    pub fn inverse_vartime(&self) -> Result<Self, Errors> {
+        let inverse = m_inverse_mod(self.value, self.modulus)?;
+        Ok(Self { value: inverse, modulus: self.modulus })
+    }
+    /// Swaps a and b if `choice` is true, without branching on `choice`.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        U256::conditional_swap(&mut a.value, &mut b.value, choice);
+    }
+    fn check_modulus(&self, other: &Self) {
+        if self.modulus != other.modulus {
+            panic!("FieldElement operands are bound to different moduli!");
+        }
+    }
+}
+
+impl Add for FieldElement {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.check_modulus(&rhs);
+        // both values are below the modulus, so a single subtraction reduces the sum
+        let (sum, overflow) = self.value.overflowing_add(rhs.value);
+        let value = match overflow || sum >= self.modulus {
+            true => sum.overflowing_sub(self.modulus).0,
+            false => sum
+        };
+        Self { value, modulus: self.modulus }
+    }
+}
+
+impl Sub for FieldElement {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.check_modulus(&rhs);
+        let value = match self.value >= rhs.value {
+            true => self.value - rhs.value,
+            false => self.modulus - (rhs.value - self.value)
+        };
+        Self { value, modulus: self.modulus }
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        self.check_modulus(&rhs);
+        let value = self.value.full_mul(rhs.value) % U512::from(self.modulus);
+        Self { value: value.into(), modulus: self.modulus }
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let value = match self.is_zero() {
+            true => self.value,
+            false => self.modulus - self.value
+        };
+        Self { value, modulus: self.modulus }
+    }
+}
+
+impl std::fmt::LowerHex for FieldElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        std::fmt::LowerHex::fmt(&self.value, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EllipticCurve;
+
+    #[test]
+    fn test_field_element() -> Result<(), Errors> {
+        let p = U256::from(11);
+        let a = FieldElement::new(U256::from(5), p);
+        let b = FieldElement::new(U256::from(20), p);
+        // values are reduced on creation
+        assert_eq!(b.value(), U256::from(9));
+        assert_eq!((a + b).value(), U256::from(3));
+        assert_eq!((a - b).value(), U256::from(7));
+        assert_eq!((a * b).value(), U256::from(1));
+        assert_eq!((-a).value(), U256::from(6));
+        assert_eq!(-FieldElement::zero(p), FieldElement::zero(p));
+        assert_eq!(FieldElement::from_i64(-3, p).value(), U256::from(8));
+        assert_eq!(a.square().value(), U256::from(3));
+        assert_eq!(a.pow(U256::from(3)).value(), U256::from(4));
+        assert_eq!(a.pow(U256::zero()), FieldElement::one(p));
+        // 5 has 9 as multiplicative inverse mod 11
+        assert_eq!(a.inverse()?, b);
+        assert_eq!(a.inverse_vartime()?, b);
+        assert_eq!(FieldElement::zero(p).inverse(), Err(Errors::ZeroDivision));
+        let (mut c, mut d) = (a, b);
+        FieldElement::conditional_swap(&mut c, &mut d, false);
+        assert_eq!((c, d), (a, b));
+        FieldElement::conditional_swap(&mut c, &mut d, true);
+        assert_eq!((c, d), (b, a));
+        // sums close to 2^256 must not overflow
+        let p = EllipticCurve::secp256k1_factory().p;
+        let minus_one = -FieldElement::one(p);
+        assert_eq!(minus_one + minus_one, -FieldElement::new(U256::from(2), p));
+        assert_eq!((minus_one * minus_one), FieldElement::one(p));
+        let k = FieldElement::new(U256::from_dec_str("51962848049517897314481377586705320001209492118704192225945377961561169702593").unwrap(), p);
+        let x = U256::from_dec_str("15770621123931935841922866852148091009166141688620356011139719709837462056333").unwrap();
+        assert_eq!(k.inverse()?.value(), x);
+        // Fermat's little theorem: k^(p-1) = 1
+        assert_eq!(k.pow(p - U256::one()), FieldElement::one(p));
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+use crate::types::{Scalar, ECpoint};
+
+/// Represents the public commitments (hiding * G, binding * G) to the nonces a FROST participant
+/// publishes in the first round of signing.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FrostCommitment {
+    pub identifier: Scalar,
+    pub hiding: ECpoint,
+    pub binding: ECpoint
+}
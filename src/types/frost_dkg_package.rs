@@ -0,0 +1,11 @@
+use crate::types::{Scalar, ECpoint};
+
+/// Represents what a participant of the FROST distributed key generation broadcasts in the first round:
+/// the Feldman commitments a_j * G to its polynomial and a Schnorr proof (R, mu) that it knows a_0.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FrostDkgPackage {
+    pub identifier: Scalar,
+    pub commitments: Vec<ECpoint>,
+    pub proof_r: ECpoint,
+    pub proof_mu: Scalar
+}
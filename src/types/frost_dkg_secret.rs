@@ -0,0 +1,9 @@
+use crate::types::Scalar;
+
+/// Represents the secret polynomial a participant of the FROST distributed key generation keeps
+/// between the rounds, the first coefficient is its contribution to the group secret.
+#[derive(Debug, PartialEq)]
+pub struct FrostDkgSecret {
+    pub(crate) identifier: Scalar,
+    pub(crate) coefficients: Vec<Scalar>
+}
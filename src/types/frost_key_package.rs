@@ -0,0 +1,11 @@
+use crate::types::{Scalar, ECpoint};
+
+/// Represents what a FROST participant holds after key generation: its identifier (a non-zero scalar),
+/// its share of the group secret, the matching public share and the group public key.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FrostKeyPackage {
+    pub identifier: Scalar,
+    pub secret_share: Scalar,
+    pub public_share: ECpoint,
+    pub group_public_key: ECpoint
+}
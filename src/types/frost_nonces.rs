@@ -0,0 +1,9 @@
+use crate::types::Scalar;
+
+/// Represents the secret hiding and binding nonces of a FROST participant for one signing session.
+/// It is not Clone or Copy, signing consumes it as reusing the nonces leaks the secret share.
+#[derive(Debug, PartialEq)]
+pub struct FrostNonces {
+    pub(crate) hiding: Scalar,
+    pub(crate) binding: Scalar
+}
@@ -0,0 +1,26 @@
+use crate::types::{Scalar, ECpoint, FrostCommitment, Errors};
+
+/// Represents the values every FROST signer and the aggregator derive from the group key, the commitments
+/// of the signers and the message: a binding factor per signer, the group commitment R and the challenge.
+/// They are derived by `frost_session`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FrostSession {
+    pub commitments: Vec<FrostCommitment>,
+    pub binding_factors: Vec<Scalar>,
+    pub group_commitment: ECpoint,
+    pub challenge: Scalar
+}
+
+impl FrostSession {
+    /// Returns the identifiers of the signers in ascending order.
+    pub fn participants(&self) -> Vec<Scalar> {
+        self.commitments.iter().map(|commitment| commitment.identifier).collect()
+    }
+    /// Returns the commitment and the binding factor of the signer, ParticipantNotInList if it does not sign.
+    pub fn signer(&self, identifier: &Scalar) -> Result<(FrostCommitment, Scalar), Errors> {
+        match self.commitments.iter().position(|commitment| commitment.identifier == *identifier) {
+            Some(i) => Ok((self.commitments[i], self.binding_factors[i])),
+            None => Err(Errors::ParticipantNotInList(identifier.value()))
+        }
+    }
+}
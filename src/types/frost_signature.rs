@@ -0,0 +1,35 @@
+use crate::types::{Scalar, ECpoint, EllipticCurve, Errors};
+
+/// Represents a FROST (RFC 9591) signature: the group commitment R and z.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FrostSignature {
+    pub r: ECpoint,
+    pub z: Scalar
+}
+
+impl FrostSignature {
+    /// Creates instance of FrostSignature from r, z.
+    pub fn new(r: ECpoint, z: Scalar) -> Self {
+        FrostSignature {r, z}
+    }
+    /// Returns the 65 byte encoding: R compressed followed by z.
+    pub fn to_bytes(&self, curve: &EllipticCurve) -> Result<[u8; 65], Errors> {
+        if self.r == ECpoint::Infinity {
+            return Err(Errors::PointAtInfinity);
+        }
+        let mut bytes = [0u8; 65];
+        bytes[..33].copy_from_slice(&self.r.to_sec1(true, curve));
+        bytes[33..].copy_from_slice(&self.z.to_bytes());
+        Ok(bytes)
+    }
+    /// Parses the 65 byte encoding, R must be a compressed point and z below n.
+    pub fn from_bytes(bytes: &[u8; 65], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let r = match bytes[0] {
+            0x02 | 0x03 => ECpoint::from_sec1(&bytes[..33], curve)?,
+            prefix => return Err(Errors::InvalidSec1Prefix(prefix))
+        };
+        let mut z = [0u8; 32];
+        z.copy_from_slice(&bytes[33..]);
+        Ok(FrostSignature::new(r, Scalar::from_bytes(&z, curve)?))
+    }
+}
@@ -0,0 +1,106 @@
+use crate::types::{U256, Point, ECpoint, EllipticCurve, JacobianPoint};
+use crate::ec_math::{jacobian_add, jacobian_double};
+
+/// Width of one window of the fixed-base table in bits.
+pub const WINDOW_BITS: usize = 4;
+/// Number of non-zero digits of a window.
+const WINDOW_SIZE: usize = (1 << WINDOW_BITS) - 1;
+
+/// Precomputed multiples of the base point of a curve, used by scalar_mult_base.
+/// Window i holds j * 16^i * G for j in 1..=15 in affine coordinates, so k * G is the sum
+/// of one entry per 4 bits of k, no doubling is needed and every addition is a mixed one.
+pub struct GeneratorTable {
+    windows: Vec<Vec<Point>>,
+    blinding: JacobianPoint,
+    /// p, a, g and n of the curve the table was built for
+    parameters: (U256, i64, (U256, U256), U256)
+}
+
+impl GeneratorTable {
+    /// Builds the table for the base point of `curve`.
+    pub fn new(curve: &EllipticCurve) -> Self {
+        let count = curve.n.bits().div_ceil(WINDOW_BITS);
+        let mut jacobian = Vec::with_capacity(count * WINDOW_SIZE);
+        let mut base = JacobianPoint::from_affine(&ECpoint::OnCurve(Point::from(curve.g)), curve);
+        for _ in 0..count {
+            let mut entry = base;
+            jacobian.push(entry);
+            for _ in 1..WINDOW_SIZE {
+                entry = jacobian_add(&entry, &base, curve);
+                jacobian.push(entry);
+            }
+            for _ in 0..WINDOW_BITS {
+                base = jacobian_double(&base, curve);
+            }
+        }
+        // all entries share one inversion, G is on the curve so the conversion can not fail
+        let affine = JacobianPoint::batch_to_affine(&jacobian, curve).unwrap();
+        // an entry is infinity only if n <= 15, then there is a single window and
+        // digits are below n, so such an entry is never selected
+        let windows = affine.chunks(WINDOW_SIZE)
+            .map(|window| window.iter().map(|p| match p {
+                ECpoint::Infinity => Point::new(U256::zero(), U256::zero()),
+                ECpoint::OnCurve(p) => *p
+            }).collect())
+            .collect();
+        // 16^count * G is the start value of the accumulator, so it is never infinity
+        Self { windows, blinding: base, parameters: (curve.p, curve.a, curve.g, curve.n) }
+    }
+    /// Returns true if the table was built from the current parameters of `curve`.
+    pub fn is_for(&self, curve: &EllipticCurve) -> bool {
+        self.parameters == (curve.p, curve.a, curve.g, curve.n)
+    }
+    /// Returns the number of windows.
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+    /// Returns true if the table has no windows.
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+    /// Returns the point the accumulator starts from (and which is subtracted at the end).
+    pub fn blinding(&self) -> JacobianPoint {
+        self.blinding
+    }
+    /// Returns digit * 16^window * G reading every entry of the window, so the memory access
+    /// pattern does not depend on the digit. For digit 0 an arbitrary entry is returned.
+    pub fn select(&self, window: usize, digit: usize) -> ECpoint {
+        let entries = &self.windows[window];
+        let mut selected = entries[0];
+        for (j, entry) in entries.iter().enumerate().skip(1) {
+            let mut candidate = *entry;
+            Point::conditional_swap(&mut selected, &mut candidate, j + 1 == digit);
+        }
+        ECpoint::OnCurve(selected)
+    }
+}
+
+impl std::fmt::Debug for GeneratorTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("GeneratorTable").field("windows", &self.windows.len()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Points, Errors};
+    use crate::ec_math::{scalar_mult, point_add};
+
+    #[test]
+    fn test_generator_table() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let table = GeneratorTable::new(&secp256k1);
+        assert_eq!(table.len(), 64);
+        #[allow(non_snake_case)]
+        let G = Points::FinitePoint(Point::from(secp256k1.g));
+        // window 2, digit 5 is 5 * 16^2 * G
+        assert_eq!(table.select(2, 5), scalar_mult(U256::from(5 * 256), &G, &secp256k1)?);
+        assert_eq!(table.select(0, 15), scalar_mult(U256::from(15), &G, &secp256k1)?);
+        assert_eq!(table.select(0, 1), scalar_mult(U256::one(), &G, &secp256k1)?);
+        // the blinding point is 16^64 * G = (2^256 - 1) * G + G
+        let max_g = scalar_mult(U256::MAX, &G, &secp256k1)?;
+        assert_eq!(table.blinding().to_affine()?, point_add(&max_g.into(), &G, &secp256k1)?);
+        Ok(())
+    }
+}
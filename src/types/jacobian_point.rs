@@ -0,0 +1,106 @@
+use crate::types::{U256, FieldElement, Point, ECpoint, EllipticCurve, Errors};
+use crate::group_math::batch_inverse;
+use std::ops::Neg;
+
+#[derive(Debug,Clone,Copy)]
+/// Represents a point in Jacobian coordinates (X, Y, Z), that is the affine point (X/Z^2, Y/Z^3).
+/// Infinity is represented by Z = 0. Used internally to add and double points without inversions.
+pub struct JacobianPoint {
+    pub x: FieldElement,
+    pub y: FieldElement,
+    pub z: FieldElement
+}
+
+impl JacobianPoint {
+    /// Returns the point at infinity (1, 1, 0).
+    pub fn infinity(curve: &EllipticCurve) -> Self {
+        Self {
+            x: FieldElement::one(curve.p),
+            y: FieldElement::one(curve.p),
+            z: FieldElement::zero(curve.p)
+        }
+    }
+    /// Converts an affine point, (x, y) becomes (x, y, 1).
+    pub fn from_affine(p: &ECpoint, curve: &EllipticCurve) -> Self {
+        match p {
+            ECpoint::Infinity => Self::infinity(curve),
+            ECpoint::OnCurve(p) => Self {
+                x: FieldElement::new(p.x, curve.p),
+                y: FieldElement::new(p.y, curve.p),
+                z: FieldElement::one(curve.p)
+            }
+        }
+    }
+    /// Returns true if the point is the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+    /// Swaps a and b if `choice` is true, without branching on `choice`.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        FieldElement::conditional_swap(&mut a.x, &mut b.x, choice);
+        FieldElement::conditional_swap(&mut a.y, &mut b.y, choice);
+        FieldElement::conditional_swap(&mut a.z, &mut b.z, choice);
+    }
+    /// Converts back to affine coordinates, this costs one inversion.
+    pub fn to_affine(&self) -> Result<ECpoint, Errors> {
+        if self.is_infinity() {
+            return Ok(ECpoint::Infinity);
+        }
+        let z_inv = self.z.inverse()?;
+        let z_inv_2 = z_inv.square();
+        let x = self.x * z_inv_2;
+        let y = self.y * z_inv_2 * z_inv;
+        Ok(ECpoint::OnCurve(Point::new(x.value(), y.value())))
+    }
+    /// Converts many points to affine coordinates sharing a single inversion.
+    pub fn batch_to_affine(points: &[Self], curve: &EllipticCurve) -> Result<Vec<ECpoint>, Errors> {
+        let mut z_inverses: Vec<U256> = points.iter().map(|p| p.z.value()).collect();
+        batch_inverse(&mut z_inverses, curve.p)?;
+        let affine = points.iter().zip(z_inverses).map(|(p, z_inv)| match p.is_infinity() {
+            true => ECpoint::Infinity,
+            false => {
+                let z_inv = FieldElement::new(z_inv, curve.p);
+                let z_inv_2 = z_inv.square();
+                let x = p.x * z_inv_2;
+                let y = p.y * z_inv_2 * z_inv;
+                ECpoint::OnCurve(Point::new(x.value(), y.value()))
+            }
+        });
+        Ok(affine.collect())
+    }
+}
+
+impl Neg for JacobianPoint {
+    type Output = Self;
+    /// -(X, Y, Z) = (X, -Y, Z)
+    fn neg(self) -> Self {
+        Self { x: self.x, y: -self.y, z: self.z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ec_math::jacobian_double;
+
+    #[test]
+    fn test_jacobian_point() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let g = ECpoint::OnCurve(Point::from(secp256k1.g));
+        assert_eq!(JacobianPoint::from_affine(&g, &secp256k1).to_affine()?, g);
+        assert!(JacobianPoint::from_affine(&ECpoint::Infinity, &secp256k1).is_infinity());
+        assert_eq!(JacobianPoint::infinity(&secp256k1).to_affine()?, ECpoint::Infinity);
+        // (X * l^2, Y * l^3, Z * l) represents the same point for any non-zero l
+        let l = FieldElement::new(U256::from(12345), secp256k1.p);
+        let JacobianPoint { x, y, z } = JacobianPoint::from_affine(&g, &secp256k1);
+        let scaled = JacobianPoint { x: x * l.square(), y: y * l.pow(U256::from(3)), z: z * l };
+        assert_eq!(scaled.to_affine()?, g);
+        let minus_g = ECpoint::OnCurve(Point::new(secp256k1.g.0, (-FieldElement::new(secp256k1.g.1, secp256k1.p)).value()));
+        assert_eq!((-scaled).to_affine()?, minus_g);
+        // batch conversion gives the same points
+        let points = [scaled, JacobianPoint::infinity(&secp256k1), jacobian_double(&scaled, &secp256k1), -scaled];
+        let expected: Vec<ECpoint> = points.iter().map(|p| p.to_affine()).collect::<Result<_, _>>()?;
+        assert_eq!(JacobianPoint::batch_to_affine(&points, &secp256k1)?, expected);
+        Ok(())
+    }
+}
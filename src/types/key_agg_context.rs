@@ -0,0 +1,67 @@
+use crate::types::{Scalar, Point, ECpoint, XOnlyPublicKey, EllipticCurve, Errors};
+
+/// Represents the result of MuSig2 (BIP327) key aggregation: the aggregate key Q of the signers' keys,
+/// their coefficients and the accumulated sign (gacc) and sum (tacc) of tweaks applied to Q.
+#[derive(Debug, PartialEq, Clone)]
+pub struct KeyAggContext {
+    pub(crate) public_keys: Vec<ECpoint>,
+    pub(crate) coefficients: Vec<Scalar>,
+    pub(crate) aggregate_key: ECpoint,
+    pub(crate) gacc: Scalar,
+    pub(crate) tacc: Scalar
+}
+
+impl KeyAggContext {
+    /// Returns the aggregate key Q (tweaks included).
+    pub fn aggregate_key(&self) -> ECpoint {
+        self.aggregate_key
+    }
+    /// Returns the x only aggregate key, the key the final signature verifies under.
+    pub fn x_only_public_key(&self, curve: &EllipticCurve) -> Result<XOnlyPublicKey, Errors> {
+        XOnlyPublicKey::from_point(&self.aggregate_key, curve)
+    }
+    /// Returns the signers' keys in the order they were aggregated.
+    pub fn public_keys(&self) -> &[ECpoint] {
+        &self.public_keys
+    }
+    /// Returns the key aggregation coefficient of `public_key`, PublicKeyNotInList if it was not aggregated.
+    pub fn coefficient(&self, public_key: &ECpoint) -> Result<Scalar, Errors> {
+        match self.public_keys.iter().position(|key| key == public_key) {
+            Some(i) => Ok(self.coefficients[i]),
+            None => Err(Errors::PublicKeyNotInList)
+        }
+    }
+    /// Returns g of BIP327: -1 if the aggregate key Q has an odd y, otherwise 1.
+    /// Signing with g * Q gives the even y key the x only aggregate key stands for.
+    pub fn key_sign(&self, curve: &EllipticCurve) -> Scalar {
+        match self.aggregate_key {
+            ECpoint::OnCurve(Point {y, ..}) if y.bit(0) => -Scalar::one(curve),
+            _ => Scalar::one(curve)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::ec_math::scalar_mult_base;
+    use crate::utils::musig_key_agg;
+
+    #[test]
+    fn test_key_agg_context() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let keys = [scalar_mult_base(U256::from(3), &secp256k1)?, scalar_mult_base(U256::from(7), &secp256k1)?];
+        let key_agg = musig_key_agg(&keys, &secp256k1)?;
+        assert_eq!(key_agg.public_keys(), &keys);
+        // the second key has the coefficient 1, a key that was not aggregated has none
+        assert_eq!(key_agg.coefficient(&keys[1])?, Scalar::one(&secp256k1));
+        assert_eq!(key_agg.coefficient(&ECpoint::Infinity), Err(Errors::PublicKeyNotInList));
+        let expected = match key_agg.aggregate_key().to_finite_point().y.bit(0) {
+            true => -Scalar::one(&secp256k1),
+            false => Scalar::one(&secp256k1)
+        };
+        assert_eq!(key_agg.key_sign(&secp256k1), expected);
+        Ok(())
+    }
+}
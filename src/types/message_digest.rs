@@ -0,0 +1,47 @@
+use crate::types::Errors;
+use ring::digest::{digest, SHA256, SHA384, SHA512};
+
+#[derive(Debug,PartialEq,Clone,Copy)]
+/// Hash function applied to a message before it is signed or verified.
+pub enum MessageDigest {
+    Sha256,
+    Sha384,
+    Sha512,
+    /// SHA-256 applied twice, as Bitcoin does for sighashes
+    DoubleSha256,
+    /// The message already is a 32 byte hash and is used as is
+    Prehashed
+}
+
+impl MessageDigest {
+    /// Returns the hash of `message`, a prehashed message must be exactly 32 bytes long.
+    pub fn hash(&self, message: &[u8]) -> Result<Vec<u8>, Errors> {
+        match self {
+            MessageDigest::Sha256 => Ok(digest(&SHA256, message).as_ref().to_vec()),
+            MessageDigest::Sha384 => Ok(digest(&SHA384, message).as_ref().to_vec()),
+            MessageDigest::Sha512 => Ok(digest(&SHA512, message).as_ref().to_vec()),
+            MessageDigest::DoubleSha256 => Ok(digest(&SHA256, digest(&SHA256, message).as_ref()).as_ref().to_vec()),
+            MessageDigest::Prehashed => match message.len() {
+                32 => Ok(message.to_vec()),
+                length => Err(Errors::InvalidPrehashLength(length))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_digest() -> Result<(), Errors> {
+        assert_eq!(hex::encode(MessageDigest::Sha256.hash(b"abc")?), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(MessageDigest::Sha384.hash(b"abc")?.len(), 48);
+        assert_eq!(MessageDigest::Sha512.hash(b"abc")?.len(), 64);
+        assert_eq!(hex::encode(MessageDigest::DoubleSha256.hash(b"hello")?), "9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50");
+        let hash = [7u8; 32];
+        assert_eq!(MessageDigest::Prehashed.hash(&hash)?, hash.to_vec());
+        assert_eq!(MessageDigest::Prehashed.hash(&hash[..31]), Err(Errors::InvalidPrehashLength(31)));
+        Ok(())
+    }
+}
@@ -2,17 +2,77 @@ pub mod u256;
 pub use self::u256::U256;
 pub mod u512;
 pub use self::u512::U512;
+pub mod field_element;
+pub use self::field_element::FieldElement;
+pub mod scalar;
+pub use self::scalar::Scalar;
 pub mod point;
 pub use self::point::Point;
 pub mod ec_point;
 pub use self::ec_point::ECpoint;
+pub mod jacobian_point;
+pub use self::jacobian_point::JacobianPoint;
 pub mod points;
 pub use self::points::Points;
+pub mod endomorphism;
+pub use self::endomorphism::Endomorphism;
+pub mod generator_table;
+pub use self::generator_table::GeneratorTable;
 pub mod eliptic_curve;
 pub use self::eliptic_curve::EllipticCurve;
 pub mod errors;
 pub use self::errors::Errors;
+pub mod schnorr_signature;
+pub use self::schnorr_signature::SchnorrSignature;
+pub mod x_only_public_key;
+pub use self::x_only_public_key::XOnlyPublicKey;
+pub mod tap_leaf;
+pub use self::tap_leaf::TapLeaf;
+pub mod tap_tree;
+pub use self::tap_tree::TapTree;
+pub mod control_block;
+pub use self::control_block::ControlBlock;
+pub mod key_agg_context;
+pub use self::key_agg_context::KeyAggContext;
+pub mod musig_secret_nonce;
+pub use self::musig_secret_nonce::MusigSecretNonce;
+pub mod musig_public_nonce;
+pub use self::musig_public_nonce::MusigPublicNonce;
+pub mod musig_aggregate_nonce;
+pub use self::musig_aggregate_nonce::MusigAggregateNonce;
+pub mod musig_session;
+pub use self::musig_session::MusigSession;
+pub mod frost_key_package;
+pub use self::frost_key_package::FrostKeyPackage;
+pub mod frost_nonces;
+pub use self::frost_nonces::FrostNonces;
+pub mod frost_commitment;
+pub use self::frost_commitment::FrostCommitment;
+pub mod frost_session;
+pub use self::frost_session::FrostSession;
+pub mod frost_signature;
+pub use self::frost_signature::FrostSignature;
+pub mod frost_dkg_secret;
+pub use self::frost_dkg_secret::FrostDkgSecret;
+pub mod frost_dkg_package;
+pub use self::frost_dkg_package::FrostDkgPackage;
+pub mod dleq_proof;
+pub use self::dleq_proof::DleqProof;
+pub mod schnorr_pre_signature;
+pub use self::schnorr_pre_signature::SchnorrPreSignature;
+pub mod ecdsa_pre_signature;
+pub use self::ecdsa_pre_signature::EcdsaPreSignature;
+pub mod der_error;
+pub use self::der_error::DerError;
 pub mod signature;
 pub use self::signature::Signature;
+pub mod recoverable_signature;
+pub use self::recoverable_signature::RecoverableSignature;
+pub mod verification_policy;
+pub use self::verification_policy::VerificationPolicy;
+pub mod message_digest;
+pub use self::message_digest::MessageDigest;
+pub mod rfc6979;
+pub use self::rfc6979::Rfc6979;
 pub mod signature_verification;
 pub use self::signature_verification::SignatureVerification;
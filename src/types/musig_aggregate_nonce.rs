@@ -0,0 +1,33 @@
+use crate::types::{ECpoint, EllipticCurve, Errors};
+
+/// Represents the sum of the signers' public nonces, either point may be the point at infinity.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MusigAggregateNonce {
+    pub r1: ECpoint,
+    pub r2: ECpoint
+}
+
+impl MusigAggregateNonce {
+    /// Creates instance of MusigAggregateNonce from r1, r2.
+    pub fn new(r1: ECpoint, r2: ECpoint) -> Self {
+        MusigAggregateNonce {r1, r2}
+    }
+    /// Returns the 66 byte encoding, both points compressed and infinity as 33 zero bytes.
+    pub fn to_bytes(&self, curve: &EllipticCurve) -> [u8; 66] {
+        let mut bytes = [0u8; 66];
+        for (point, chunk) in [self.r1, self.r2].iter().zip(bytes.chunks_mut(33)) {
+            if let ECpoint::OnCurve(_) = point {
+                chunk.copy_from_slice(&point.to_sec1(true, curve));
+            }
+        }
+        bytes
+    }
+    /// Parses the 66 byte encoding.
+    pub fn from_bytes(bytes: &[u8; 66], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let parse = |chunk: &[u8]| match chunk.iter().all(|byte| *byte == 0) {
+            true => Ok(ECpoint::Infinity),
+            false => ECpoint::from_sec1(chunk, curve)
+        };
+        Ok(MusigAggregateNonce::new(parse(&bytes[..33])?, parse(&bytes[33..])?))
+    }
+}
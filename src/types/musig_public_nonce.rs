@@ -0,0 +1,47 @@
+use crate::types::{ECpoint, EllipticCurve, Errors};
+
+/// Represents the public nonce (R1, R2) a MuSig2 signer sends in the first round.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MusigPublicNonce {
+    pub r1: ECpoint,
+    pub r2: ECpoint
+}
+
+impl MusigPublicNonce {
+    /// Creates instance of MusigPublicNonce from r1, r2.
+    pub fn new(r1: ECpoint, r2: ECpoint) -> Self {
+        MusigPublicNonce {r1, r2}
+    }
+    /// Returns the 66 byte encoding, both points compressed. Returns PointAtInfinity if one of them is infinity.
+    pub fn to_bytes(&self, curve: &EllipticCurve) -> Result<[u8; 66], Errors> {
+        if self.r1 == ECpoint::Infinity || self.r2 == ECpoint::Infinity {
+            return Err(Errors::PointAtInfinity);
+        }
+        let mut bytes = [0u8; 66];
+        bytes[..33].copy_from_slice(&self.r1.to_sec1(true, curve));
+        bytes[33..].copy_from_slice(&self.r2.to_sec1(true, curve));
+        Ok(bytes)
+    }
+    /// Parses the 66 byte encoding, both halves must be compressed points of the curve.
+    pub fn from_bytes(bytes: &[u8; 66], curve: &EllipticCurve) -> Result<Self, Errors> {
+        Ok(MusigPublicNonce::new(ECpoint::from_sec1(&bytes[..33], curve)?, ECpoint::from_sec1(&bytes[33..], curve)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::ec_math::scalar_mult_base;
+
+    #[test]
+    fn test_musig_public_nonce() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let (r1, r2) = (scalar_mult_base(U256::from(2), &secp256k1)?, scalar_mult_base(U256::from(3), &secp256k1)?);
+        let nonce = MusigPublicNonce::new(r1, r2);
+        assert_eq!(MusigPublicNonce::from_bytes(&nonce.to_bytes(&secp256k1)?, &secp256k1)?, nonce);
+        assert_eq!(MusigPublicNonce::new(r1, ECpoint::Infinity).to_bytes(&secp256k1), Err(Errors::PointAtInfinity));
+        assert_eq!(MusigPublicNonce::new(ECpoint::Infinity, r2).to_bytes(&secp256k1), Err(Errors::PointAtInfinity));
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::types::{Scalar, ECpoint, EllipticCurve, Errors};
+
+/// Represents the secret nonce (k1, k2) of a MuSig2 signer together with the signer's public key.
+/// It is not Clone or Copy: signing consumes it, as signing twice with the same nonce leaks the private key.
+#[derive(Debug, PartialEq)]
+pub struct MusigSecretNonce {
+    pub(crate) k1: Scalar,
+    pub(crate) k2: Scalar,
+    pub(crate) public_key: ECpoint
+}
+
+impl MusigSecretNonce {
+    /// Returns the 97 byte encoding k1 || k2 || compressed public key, PointAtInfinity if the key is infinity.
+    pub fn to_bytes(&self, curve: &EllipticCurve) -> Result<[u8; 97], Errors> {
+        if self.public_key == ECpoint::Infinity {
+            return Err(Errors::PointAtInfinity);
+        }
+        let mut bytes = [0u8; 97];
+        bytes[..32].copy_from_slice(&self.k1.to_bytes());
+        bytes[32..64].copy_from_slice(&self.k2.to_bytes());
+        bytes[64..].copy_from_slice(&self.public_key.to_sec1(true, curve));
+        Ok(bytes)
+    }
+    /// Parses the 97 byte encoding, k1 and k2 must be below n.
+    pub fn from_bytes(bytes: &[u8; 97], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let mut k = [0u8; 32];
+        k.copy_from_slice(&bytes[..32]);
+        let k1 = Scalar::from_bytes(&k, curve)?;
+        k.copy_from_slice(&bytes[32..64]);
+        let k2 = Scalar::from_bytes(&k, curve)?;
+        Ok(MusigSecretNonce {k1, k2, public_key: ECpoint::from_sec1(&bytes[64..], curve)?})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::ec_math::scalar_mult_base;
+
+    #[test]
+    fn test_musig_secret_nonce() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let (k1, k2) = (Scalar::new(U256::from(2), &secp256k1), Scalar::new(U256::from(3), &secp256k1));
+        let nonce = MusigSecretNonce {k1, k2, public_key: scalar_mult_base(U256::from(5), &secp256k1)?};
+        assert_eq!(MusigSecretNonce::from_bytes(&nonce.to_bytes(&secp256k1)?, &secp256k1)?, nonce);
+        let nonce = MusigSecretNonce {k1, k2, public_key: ECpoint::Infinity};
+        assert_eq!(nonce.to_bytes(&secp256k1), Err(Errors::PointAtInfinity));
+        Ok(())
+    }
+}
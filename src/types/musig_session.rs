@@ -0,0 +1,11 @@
+use crate::types::{Scalar, Point};
+
+/// Represents the values every MuSig2 signer derives from the aggregate nonce, the keys and the message:
+/// the nonce coefficient b, the final nonce point R = R1 + b * R2 and the BIP340 challenge e.
+/// They are derived by `musig_session`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MusigSession {
+    pub nonce_coefficient: Scalar,
+    pub nonce_point: Point,
+    pub challenge: Scalar
+}
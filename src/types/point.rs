@@ -11,6 +11,11 @@ impl Point {
     pub fn new(x: U256, y: U256) -> Self {
         Self {x, y}
     }
+    /// Swaps a and b if `choice` is true, without branching on `choice`.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        U256::conditional_swap(&mut a.x, &mut b.x, choice);
+        U256::conditional_swap(&mut a.y, &mut b.y, choice);
+    }
 }
 
 impl std::convert::From<(U256, U256)> for Point {
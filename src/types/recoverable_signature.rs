@@ -0,0 +1,78 @@
+use crate::types::{U256, Signature, EllipticCurve, Errors};
+
+/// Represents an EC signature together with the recovery id, which is enough to
+/// recover the public key of the signer (as Bitcoin's signmessage and Ethereum do).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RecoverableSignature {
+    pub signature: Signature,
+    /// bit 0 is the parity of y of the nonce point R, bit 1 is set if x of R is r + n instead of r
+    pub recovery_id: u8
+}
+
+impl RecoverableSignature {
+    /// Creates instance of RecoverableSignature from the signature and the recovery id.
+    pub fn new(signature: Signature, recovery_id: u8) -> Self {
+        RecoverableSignature {signature, recovery_id}
+    }
+    /// Returns the recovery id of the nonce point (x, y).
+    /// Only x < 2n can be encoded, which holds for curves with cofactor 1 like secp256k1.
+    pub(crate) fn recovery_id(x: U256, y: U256, curve: &EllipticCurve) -> u8 {
+        let overflow = x >= curve.n;
+        ((overflow as u8) << 1) | y.bit(0) as u8
+    }
+    /// Returns the 65 byte compact form used by Bitcoin's signmessage: a header byte
+    /// 27 + recovery id (+ 4 if the public key is compressed), then r and s as 32 bytes each.
+    pub fn to_compact(&self, compressed: bool) -> [u8; 65] {
+        let mut compact = [0u8; 65];
+        compact[0] = 27 + self.recovery_id + if compressed { 4 } else { 0 };
+        compact[1..33].copy_from_slice(&self.signature.r.to_bytes());
+        compact[33..].copy_from_slice(&self.signature.s.to_bytes());
+        compact
+    }
+    /// Parses the compact form, returns the signature and whether the public key is compressed.
+    /// r and s must be below n (ScalarOutOfRange).
+    pub fn from_compact(bytes: &[u8], curve: &EllipticCurve) -> Result<(Self, bool), Errors> {
+        if bytes.len() != 65 {
+            return Err(Errors::InvalidCompactLength(bytes.len()));
+        }
+        let header = bytes[0];
+        if !(27..35).contains(&header) {
+            return Err(Errors::InvalidCompactHeader(header));
+        }
+        let signature = Signature::from_u256(U256::from_big_endian(&bytes[1..33]), U256::from_big_endian(&bytes[33..]), curve)?;
+        Ok((RecoverableSignature::new(signature, (header - 27) & 3), header >= 31))
+    }
+}
+
+impl std::fmt::LowerHex for RecoverableSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{:#x}, recovery id {}", self.signature, self.recovery_id)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let sig = RecoverableSignature::new(Signature::from_u256(U256::one(), U256::from(2), &secp256k1)?, 1);
+        let compact = sig.to_compact(true);
+        assert_eq!(compact[0], 32);
+        assert_eq!(compact[32], 1);
+        assert_eq!(compact[64], 2);
+        assert_eq!(RecoverableSignature::from_compact(&compact, &secp256k1)?, (sig, true));
+        assert_eq!(sig.to_compact(false)[0], 28);
+        assert_eq!(RecoverableSignature::from_compact(&sig.to_compact(false), &secp256k1)?, (sig, false));
+        assert_eq!(RecoverableSignature::from_compact(&compact[..64], &secp256k1), Err(Errors::InvalidCompactLength(64)));
+        let mut bad_header = compact;
+        bad_header[0] = 35;
+        assert_eq!(RecoverableSignature::from_compact(&bad_header, &secp256k1), Err(Errors::InvalidCompactHeader(35)));
+        let mut s_too_large = compact;
+        s_too_large[33..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(RecoverableSignature::from_compact(&s_too_large, &secp256k1), Err(Errors::ScalarOutOfRange(U256::MAX)));
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::types::{U256, Scalar, EllipticCurve};
+use crate::traits::GenRandU256;
+use ring::hmac;
+
+/// Deterministic nonce generator of RFC 6979 (HMAC-DRBG with HMAC-SHA256), seeded from the
+/// private key and the message hash. Used through GenRandU256, `gen_u256_range(1, n)` returns
+/// the nonce k of section 3.2, every further call returns the next candidate (step h.3).
+pub struct Rfc6979 {
+    k: [u8; 32],
+    v: [u8; 32],
+    /// bit length of n
+    qlen: usize,
+    started: bool
+}
+
+impl Rfc6979 {
+    /// `z` is the message hash already converted with bits2int (truncated to the bit length of n),
+    /// `extra_entropy` is appended to the seed as described in section 3.6.
+    pub fn new(private_key: &Scalar, z: U256, extra_entropy: Option<&[u8]>, curve: &EllipticCurve) -> Self {
+        let qlen = curve.n.bits();
+        let x = Self::int2octets(private_key.value(), qlen);
+        let h = Self::int2octets(z % curve.n, qlen);
+        let extra_entropy = extra_entropy.unwrap_or(&[]);
+        let v = [0x01; 32];
+        let k = Self::hmac_sha256(&[0x00; 32], &[&v, &[0x00], &x, &h, extra_entropy]);
+        let v = Self::hmac_sha256(&k, &[&v]);
+        let k = Self::hmac_sha256(&k, &[&v, &[0x01], &x, &h, extra_entropy]);
+        let v = Self::hmac_sha256(&k, &[&v]);
+        Rfc6979 { k, v, qlen, started: false }
+    }
+    fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, key);
+        let mut context = hmac::Context::with_key(&key);
+        for part in parts.iter() {
+            context.update(part);
+        }
+        let mut tag = [0u8; 32];
+        tag.copy_from_slice(context.sign().as_ref());
+        tag
+    }
+    /// Big endian encoding with as many bytes as n has.
+    fn int2octets(value: U256, qlen: usize) -> Vec<u8> {
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        bytes[32 - qlen.div_ceil(8)..].to_vec()
+    }
+}
+
+impl GenRandU256 for Rfc6979 {
+    /// Returns bits2int of the next HMAC-DRBG output, i.e. its leftmost qlen bits.
+    fn gen_u256(&mut self) -> U256 {
+        if self.started {
+            self.k = Self::hmac_sha256(&self.k, &[&self.v, &[0x00]]);
+            self.v = Self::hmac_sha256(&self.k, &[&self.v]);
+        }
+        self.started = true;
+        self.v = Self::hmac_sha256(&self.k, &[&self.v]);
+        U256::from_big_endian(&self.v) >> (256 - self.qlen)
+    }
+    fn gen_u256_range(&mut self, low: &U256, high: &U256) -> U256 {
+        loop {
+            let candidate = self.gen_u256();
+            if candidate >= *low && candidate < *high {
+                return candidate;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::digest::{digest, SHA256};
+
+    fn sha256(message: &[u8]) -> U256 {
+        U256::from_big_endian(digest(&SHA256, message).as_ref())
+    }
+
+    fn nonce(private_key: &'static str, z: U256, extra_entropy: Option<&[u8]>, curve: &EllipticCurve) -> U256 {
+        let private_key = Scalar::new(U256::from(private_key), curve);
+        Rfc6979::new(&private_key, z, extra_entropy, curve).gen_u256_range(&U256::one(), &curve.n)
+    }
+
+    #[test]
+    fn test_rfc6979() {
+        // RFC 6979 A.2.5 (P-256, SHA-256), only n is needed to derive the nonce
+        let mut p256 = EllipticCurve::new("P-256");
+        p256.set_n("0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        let x = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+        assert_eq!(nonce(x, sha256(b"sample"), None, &p256), U256::from("a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60"));
+        assert_eq!(nonce(x, sha256(b"test"), None, &p256), U256::from("d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0"));
+        // RFC 6979 A.2.3 (P-192, SHA-256), the hash is longer than n
+        let mut p192 = EllipticCurve::new("P-192");
+        p192.set_n("0xffffffffffffffffffffffff99def836146bc9b1b4d22831");
+        let x = "6fab034934e4c0fc9ae67f5b5659a9d7d1fefd187ee09fd4";
+        assert_eq!(nonce(x, sha256(b"sample") >> 64, None, &p192), U256::from("32b1b6d7d42a05cb449065727a84804fb1a3e34d8f261496"));
+        // secp256k1 with private key 1, extra entropy changes the nonce
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let z = sha256(b"Satoshi Nakamoto");
+        assert_eq!(nonce("1", z, None, &secp256k1), U256::from("8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15"));
+        assert_eq!(nonce("1", z, Some(&[0x01; 32]), &secp256k1), U256::from("ff49282725ee554d481ee92230ebf201d5137cdc427fcda67210387e20a1b90b"));
+        // candidates of a tiny n are only a few bits long
+        let mut tutorial = EllipticCurve::new("tutorial");
+        tutorial.set_n("5");
+        for message in [&b"a"[..], b"b", b"c", b"d"].iter() {
+            let k = nonce("3", sha256(message) >> 253, None, &tutorial);
+            assert!(k >= U256::one() && k < U256::from(5));
+        }
+    }
+}
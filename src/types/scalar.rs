@@ -0,0 +1,129 @@
+use crate::types::{U256, FieldElement, EllipticCurve, Errors};
+use std::ops::{Add, Sub, Mul, Neg};
+
+#[derive(Debug,PartialEq,Eq,Clone,Copy)]
+/// Represents an integer modulo the subgroup order n of a curve (private keys, nonces, r, s, ...).
+/// The value is always kept reduced, so it lies in [0, n).
+pub struct Scalar(FieldElement);
+
+impl Scalar {
+    /// Creates a scalar from `value` reduced modulo `curve.n`.
+    pub fn new(value: U256, curve: &EllipticCurve) -> Self {
+        Scalar(FieldElement::new(value, curve.n))
+    }
+    /// Returns the scalar 0 of `curve`.
+    pub fn zero(curve: &EllipticCurve) -> Self {
+        Scalar(FieldElement::zero(curve.n))
+    }
+    /// Returns the scalar 1 of `curve`.
+    pub fn one(curve: &EllipticCurve) -> Self {
+        Scalar(FieldElement::one(curve.n))
+    }
+    /// Returns the reduced value.
+    pub fn value(&self) -> U256 {
+        self.0.value()
+    }
+    /// Returns true if the scalar is 0 (mod n).
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+    /// Returns the multiplicative inverse mod n by a fixed exponentiation chain, see FieldElement::inverse.
+    pub fn inverse(&self) -> Result<Self, Errors> {
+        Ok(Scalar(self.0.inverse()?))
+    }
+    /// Returns the multiplicative inverse mod n in variable time, only for public values.
+    pub fn inverse_vartime(&self) -> Result<Self, Errors> {
+        Ok(Scalar(self.0.inverse_vartime()?))
+    }
+    /// Returns the 32 byte big endian encoding.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        self.value().to_big_endian(&mut bytes);
+        bytes
+    }
+    /// Parses a 32 byte big endian encoding, values not below n are rejected.
+    pub fn from_bytes(bytes: &[u8; 32], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let value = U256::from_big_endian(bytes);
+        match value < curve.n {
+            true => Ok(Scalar::new(value, curve)),
+            false => Err(Errors::ScalarOutOfRange(value))
+        }
+    }
+    /// Parses a 32 byte big endian encoding reducing it modulo n (e.g. for hash outputs).
+    pub fn from_bytes_reduced(bytes: &[u8; 32], curve: &EllipticCurve) -> Self {
+        Scalar::new(U256::from_big_endian(bytes), curve)
+    }
+}
+
+impl std::convert::From<Scalar> for U256 {
+    fn from(s: Scalar) -> Self {
+        s.value()
+    }
+}
+
+impl Add for Scalar {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Scalar(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Scalar(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Scalar(self.0 * rhs.0)
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Scalar(-self.0)
+    }
+}
+
+impl std::fmt::LowerHex for Scalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        std::fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let n = secp256k1.n;
+        // n + 5 is reduced to 5
+        let five = Scalar::new(n + U256::from(5), &secp256k1);
+        assert_eq!(five.value(), U256::from(5));
+        assert!(Scalar::new(n, &secp256k1).is_zero());
+        let minus_one = -Scalar::one(&secp256k1);
+        assert_eq!(minus_one.value(), n - U256::one());
+        assert_eq!(minus_one + five, Scalar::new(U256::from(4), &secp256k1));
+        assert_eq!(five - minus_one, Scalar::new(U256::from(6), &secp256k1));
+        assert_eq!(minus_one * minus_one, Scalar::one(&secp256k1));
+        assert_eq!(five * five.inverse()?, Scalar::one(&secp256k1));
+        assert_eq!(five.inverse_vartime()?, five.inverse()?);
+        assert_eq!(Scalar::zero(&secp256k1).inverse(), Err(Errors::ZeroDivision));
+        // byte encoding round trips and rejects values >= n
+        let bytes = minus_one.to_bytes();
+        assert_eq!(bytes[0], 0xff);
+        assert_eq!(bytes[31], 0x40);
+        assert_eq!(Scalar::from_bytes(&bytes, &secp256k1)?, minus_one);
+        let mut n_bytes = [0u8; 32];
+        n.to_big_endian(&mut n_bytes);
+        assert_eq!(Scalar::from_bytes(&n_bytes, &secp256k1), Err(Errors::ScalarOutOfRange(n)));
+        assert!(Scalar::from_bytes_reduced(&n_bytes, &secp256k1).is_zero());
+        Ok(())
+    }
+}
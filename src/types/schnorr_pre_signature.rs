@@ -0,0 +1,37 @@
+use crate::types::{Scalar, ECpoint, EllipticCurve, Errors};
+
+/// Represents a Schnorr adaptor (pre) signature for an adaptor point T: the final nonce point R = k * G + T
+/// and s' = s - t. Adding the secret t of T turns it into a BIP340 signature (r = x(R), s),
+/// seeing both reveals t. R keeps its parity, as it decides the sign of t.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SchnorrPreSignature {
+    pub r: ECpoint,
+    pub s: Scalar
+}
+
+impl SchnorrPreSignature {
+    /// Creates instance of SchnorrPreSignature from r, s.
+    pub fn new(r: ECpoint, s: Scalar) -> Self {
+        SchnorrPreSignature {r, s}
+    }
+    /// Returns the 65 byte encoding: R compressed followed by s'.
+    pub fn to_bytes(&self, curve: &EllipticCurve) -> Result<[u8; 65], Errors> {
+        if self.r == ECpoint::Infinity {
+            return Err(Errors::PointAtInfinity);
+        }
+        let mut bytes = [0u8; 65];
+        bytes[..33].copy_from_slice(&self.r.to_sec1(true, curve));
+        bytes[33..].copy_from_slice(&self.s.to_bytes());
+        Ok(bytes)
+    }
+    /// Parses the 65 byte encoding, R must be a compressed point and s' below n.
+    pub fn from_bytes(bytes: &[u8; 65], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let r = match bytes[0] {
+            0x02 | 0x03 => ECpoint::from_sec1(&bytes[..33], curve)?,
+            prefix => return Err(Errors::InvalidSec1Prefix(prefix))
+        };
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&bytes[33..]);
+        Ok(SchnorrPreSignature::new(r, Scalar::from_bytes(&s, curve)?))
+    }
+}
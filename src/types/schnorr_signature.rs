@@ -0,0 +1,34 @@
+use crate::types::U256;
+
+/// Represents a BIP340 Schnorr signature: x coordinate of the nonce point R and s.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SchnorrSignature {
+    pub r: U256,
+    pub s: U256
+}
+
+impl SchnorrSignature {
+    /// Creates instance of SchnorrSignature from r, s.
+    pub fn new(r: U256, s: U256) -> Self {
+        SchnorrSignature {r, s}
+    }
+    /// Returns the 64 byte encoding r || s.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        self.r.to_big_endian(&mut bytes[..32]);
+        self.s.to_big_endian(&mut bytes[32..]);
+        bytes
+    }
+    /// Parses the 64 byte encoding, the ranges of r and s are checked by verification.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        SchnorrSignature::new(U256::from_big_endian(&bytes[..32]), U256::from_big_endian(&bytes[32..]))
+    }
+}
+
+impl std::fmt::LowerHex for SchnorrSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let SchnorrSignature {r, s} = self;
+        write!(f, "({:#x}, {:#x})", r, s)?;
+        Ok(())
+    }
+}
@@ -1,22 +1,153 @@
-use crate::types::U256;
+use crate::types::{U256, Scalar, Errors, DerError, EllipticCurve};
 
-/// Represents an EC signature.
-#[derive(Debug, PartialEq)]
+/// Represents an EC signature, r and s are scalars mod n (zero is representable, verification rejects it).
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Signature {
-    pub r: U256,
-    pub s: U256
+    pub r: Scalar,
+    pub s: Scalar
 }
 
 impl Signature {
     /// Creates instance of Signature from r, s.
-    pub fn new(r: U256, s: U256) -> Self {
+    pub fn new(r: Scalar, s: Scalar) -> Self {
         Signature {r, s}
     }
-}
-
-impl std::convert::From<(U256, U256)> for Signature {
-    fn from(tuple: (U256, U256)) -> Self {
-        Signature::new(tuple.0, tuple.1)
+    /// Creates a Signature from the integers r, s, returns ScalarOutOfRange if one of them is not below n.
+    pub fn from_u256(r: U256, s: U256, curve: &EllipticCurve) -> Result<Self, Errors> {
+        for value in [r, s].iter() {
+            if *value >= curve.n {
+                return Err(Errors::ScalarOutOfRange(*value));
+            }
+        }
+        Ok(Signature::new(Scalar::new(r, curve), Scalar::new(s, curve)))
+    }
+    /// Returns true if s is at most n/2.
+    pub fn is_low_s(&self, curve: &EllipticCurve) -> bool {
+        self.s.value() <= curve.n >> 1
+    }
+    /// Returns the low-S form of the signature, replacing s by n - s if s is above n/2.
+    /// Both forms verify, so this only removes the malleability.
+    pub fn normalize_s(&self, curve: &EllipticCurve) -> Self {
+        match self.is_low_s(curve) {
+            true => *self,
+            false => Signature::new(self.r, -self.s)
+        }
+    }
+    /// Returns the ASN.1 DER encoding 0x30 len 0x02 len(r) r 0x02 len(s) s, with minimal integers.
+    pub fn to_der(&self) -> Vec<u8> {
+        let r = Self::der_integer(self.r.value());
+        let s = Self::der_integer(self.s.value());
+        let mut der = vec![0x30, (r.len() + s.len()) as u8];
+        der.extend(r);
+        der.extend(s);
+        der
+    }
+    /// Parses a DER signature following the strict rules of BIP66 (without the sighash byte):
+    /// single byte lengths, no trailing data, no negative or needlessly padded integers.
+    /// r and s must be below n (ScalarOutOfRange).
+    pub fn from_der(bytes: &[u8], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let length = bytes.len();
+        if length < 8 {
+            return Err(Errors::InvalidDer(DerError::TooShort(length)));
+        }
+        if length > 72 {
+            return Err(Errors::InvalidDer(DerError::TooLong(length)));
+        }
+        if bytes[0] != 0x30 {
+            return Err(Errors::InvalidDer(DerError::NotASequence(bytes[0])));
+        }
+        if bytes[1] as usize != length - 2 {
+            return Err(Errors::InvalidDer(DerError::SequenceLengthMismatch(bytes[1] as usize, length - 2)));
+        }
+        let r_length = bytes[3] as usize;
+        if 5 + r_length >= length || r_length + bytes[5 + r_length] as usize + 6 != length {
+            return Err(Errors::InvalidDer(DerError::IntegerLengthMismatch));
+        }
+        let r = Self::strict_integer(bytes, 2)?;
+        let s = Self::strict_integer(bytes, 4 + r_length)?;
+        Signature::from_u256(r, s, curve)
+    }
+    /// Parses a DER signature the way old Bitcoin software did: long form lengths, padded
+    /// and negative integers (read as unsigned) and trailing data are all accepted. r and s must still be below n.
+    pub fn from_der_lax(bytes: &[u8], curve: &EllipticCurve) -> Result<Self, Errors> {
+        match bytes.first() {
+            Some(0x30) => (),
+            Some(tag) => return Err(Errors::InvalidDer(DerError::NotASequence(*tag))),
+            None => return Err(Errors::InvalidDer(DerError::UnexpectedEnd))
+        }
+        let mut position = 1;
+        // the sequence length is not trusted, r and s carry their own lengths
+        Self::lax_length(bytes, &mut position)?;
+        let r = Self::lax_integer(bytes, &mut position)?;
+        let s = Self::lax_integer(bytes, &mut position)?;
+        Signature::from_u256(r, s, curve)
+    }
+    fn der_integer(value: U256) -> Vec<u8> {
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(31);
+        let mut integer = vec![0x02, (32 - start) as u8];
+        if bytes[start] & 0x80 != 0 {
+            integer[1] += 1;
+            integer.push(0x00);
+        }
+        integer.extend_from_slice(&bytes[start..]);
+        integer
+    }
+    /// Checks the integer whose tag is at `offset`, the lengths are already known to fit.
+    fn strict_integer(bytes: &[u8], offset: usize) -> Result<U256, Errors> {
+        let length = bytes[offset + 1] as usize;
+        let value = &bytes[offset + 2..offset + 2 + length];
+        if bytes[offset] != 0x02 {
+            return Err(Errors::InvalidDer(DerError::NotAnInteger(offset)));
+        }
+        if length == 0 {
+            return Err(Errors::InvalidDer(DerError::ZeroLengthInteger(offset)));
+        }
+        if value[0] & 0x80 != 0 {
+            return Err(Errors::InvalidDer(DerError::NegativeInteger(offset)));
+        }
+        if length > 1 && value[0] == 0 && value[1] & 0x80 == 0 {
+            return Err(Errors::InvalidDer(DerError::ExcessPadding(offset)));
+        }
+        Self::unsigned_integer(value, offset)
+    }
+    fn lax_length(bytes: &[u8], position: &mut usize) -> Result<usize, Errors> {
+        let first = *bytes.get(*position).ok_or(Errors::InvalidDer(DerError::UnexpectedEnd))?;
+        let offset = *position;
+        *position += 1;
+        if first & 0x80 == 0 {
+            return Ok(first as usize);
+        }
+        let count = (first & 0x7f) as usize;
+        let length_bytes = bytes.get(*position..*position + count).ok_or(Errors::InvalidDer(DerError::UnexpectedEnd))?;
+        *position += count;
+        let mut length = 0usize;
+        for byte in length_bytes.iter() {
+            length = length.checked_mul(256).ok_or(Errors::InvalidDer(DerError::InvalidLength(offset)))? + *byte as usize;
+        }
+        Ok(length)
+    }
+    fn lax_integer(bytes: &[u8], position: &mut usize) -> Result<U256, Errors> {
+        let offset = *position;
+        match bytes.get(offset) {
+            Some(0x02) => (),
+            Some(_) => return Err(Errors::InvalidDer(DerError::NotAnInteger(offset))),
+            None => return Err(Errors::InvalidDer(DerError::UnexpectedEnd))
+        }
+        *position += 1;
+        let length = Self::lax_length(bytes, position)?;
+        let value = bytes.get(*position..position.saturating_add(length)).ok_or(Errors::InvalidDer(DerError::UnexpectedEnd))?;
+        *position += length;
+        Self::unsigned_integer(value, offset)
+    }
+    /// Reads big endian bytes ignoring leading zeros.
+    fn unsigned_integer(value: &[u8], offset: usize) -> Result<U256, Errors> {
+        let start = value.iter().position(|byte| *byte != 0).unwrap_or(value.len());
+        match value.len() - start > 32 {
+            true => Err(Errors::InvalidDer(DerError::IntegerTooLarge(offset))),
+            false => Ok(U256::from_big_endian(&value[start..]))
+        }
     }
 }
 
@@ -27,3 +158,83 @@ impl std::fmt::LowerHex for Signature {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn der(s: &str) -> Vec<u8> {
+        hex::decode(s).unwrap()
+    }
+
+    #[test]
+    fn test_der() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let c = &secp256k1;
+        let sig = |r: U256, s: U256| Signature::new(Scalar::new(r, c), Scalar::new(s, c));
+        // minimal integers, a zero byte is added when the high bit is set
+        let one = sig(U256::one(), U256::from(0x80));
+        assert_eq!(hex::encode(one.to_der()), "300702010102020080");
+        assert_eq!(Signature::from_der(&one.to_der(), c)?, one);
+        let zero = sig(U256::zero(), U256::zero());
+        assert_eq!(hex::encode(zero.to_der()), "3006020100020100");
+        let max = sig(secp256k1.n - U256::one(), U256::from(0x7f) << 248);
+        let encoded = max.to_der();
+        assert_eq!(encoded.len(), 71);
+        assert_eq!(Signature::from_der(&encoded, c)?, max);
+        // well formed, but r is not below n
+        let mut n = [0u8; 32];
+        secp256k1.n.to_big_endian(&mut n);
+        let mut out_of_range = der("3026022100");
+        out_of_range.extend_from_slice(&n);
+        out_of_range.extend_from_slice(&der("020101"));
+        assert_eq!(Signature::from_der(&out_of_range, c), Err(Errors::ScalarOutOfRange(secp256k1.n)));
+        assert_eq!(Signature::from_der_lax(&out_of_range, c), Err(Errors::ScalarOutOfRange(secp256k1.n)));
+        // strict parsing errors
+        let e = |error| Err(Errors::InvalidDer(error));
+        assert_eq!(Signature::from_der(&der("30050201010201"), c), e(DerError::TooShort(7)));
+        assert_eq!(Signature::from_der(&[0x30; 73], c), e(DerError::TooLong(73)));
+        assert_eq!(Signature::from_der(&der("3106020101020101"), c), e(DerError::NotASequence(0x31)));
+        assert_eq!(Signature::from_der(&der("3007020101020101"), c), e(DerError::SequenceLengthMismatch(7, 6)));
+        assert_eq!(Signature::from_der(&der("300602010102010100"), c), e(DerError::SequenceLengthMismatch(6, 7)));
+        assert_eq!(Signature::from_der(&der("3006020201020101"), c), e(DerError::IntegerLengthMismatch));
+        assert_eq!(Signature::from_der(&der("3006020101020201"), c), e(DerError::IntegerLengthMismatch));
+        assert_eq!(Signature::from_der(&der("3006030101020101"), c), e(DerError::NotAnInteger(2)));
+        assert_eq!(Signature::from_der(&der("3006020101030101"), c), e(DerError::NotAnInteger(5)));
+        assert_eq!(Signature::from_der(&der("30070200020301ff01"), c), e(DerError::ZeroLengthInteger(2)));
+        assert_eq!(Signature::from_der(&der("3006020181020101"), c), e(DerError::NegativeInteger(2)));
+        assert_eq!(Signature::from_der(&der("300702020001020101"), c), e(DerError::ExcessPadding(2)));
+        assert_eq!(Signature::from_der(&der("300702010102020001"), c), e(DerError::ExcessPadding(5)));
+        let mut too_large = der("302702220100");
+        too_large.extend_from_slice(&[0u8; 32]);
+        too_large.extend_from_slice(&der("020101"));
+        assert_eq!(Signature::from_der(&too_large, c), e(DerError::IntegerTooLarge(2)));
+        // lax parsing accepts what BIP66 rejects
+        let one = sig(U256::one(), U256::one());
+        assert_eq!(Signature::from_der_lax(&one.to_der(), c)?, one);
+        assert_eq!(Signature::from_der_lax(&der("30810602810101020101"), c)?, one);
+        assert_eq!(Signature::from_der_lax(&der("3008020300000102020001"), c)?, one);
+        assert_eq!(Signature::from_der_lax(&der("3000020101020101ffff"), c)?, one);
+        assert_eq!(Signature::from_der_lax(&der("3006020181020101"), c)?, sig(U256::from(0x81), U256::one()));
+        assert_eq!(Signature::from_der_lax(&too_large, c), e(DerError::IntegerTooLarge(2)));
+        assert_eq!(Signature::from_der_lax(&der("3006020101020201"), c), e(DerError::UnexpectedEnd));
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_s() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let half_n = secp256k1.n >> 1;
+        let low = Signature::from_u256(U256::one(), half_n, &secp256k1)?;
+        assert!(low.is_low_s(&secp256k1));
+        assert_eq!(low.normalize_s(&secp256k1), low);
+        let high = Signature::from_u256(U256::one(), half_n + U256::one(), &secp256k1)?;
+        assert!(!high.is_low_s(&secp256k1));
+        assert_eq!(high.normalize_s(&secp256k1), low);
+        let high = Signature::from_u256(U256::one(), secp256k1.n - U256::one(), &secp256k1)?;
+        assert_eq!(high.normalize_s(&secp256k1), Signature::from_u256(U256::one(), U256::one(), &secp256k1)?);
+        assert_eq!(Signature::from_u256(secp256k1.n, U256::one(), &secp256k1), Err(Errors::ScalarOutOfRange(secp256k1.n)));
+        assert_eq!(Signature::from_u256(U256::one(), U256::MAX, &secp256k1), Err(Errors::ScalarOutOfRange(U256::MAX)));
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::types::Errors;
+
+/// Represents a leaf of a taproot script tree: a script and the version it is interpreted with.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TapLeaf {
+    pub version: u8,
+    pub script: Vec<u8>
+}
+
+impl TapLeaf {
+    /// Version of BIP342 tapscript leaves.
+    pub const TAPSCRIPT: u8 = 0xc0;
+
+    /// Creates a leaf, the version must be even as its lowest bit carries the parity in control blocks.
+    pub fn new(version: u8, script: Vec<u8>) -> Result<Self, Errors> {
+        match version & 1 {
+            0 => Ok(TapLeaf {version, script}),
+            _ => Err(Errors::InvalidLeafVersion(version))
+        }
+    }
+    /// Creates a tapscript (version 0xc0) leaf.
+    pub fn tapscript(script: Vec<u8>) -> Self {
+        TapLeaf {version: TapLeaf::TAPSCRIPT, script}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_leaf() -> Result<(), Errors> {
+        assert_eq!(TapLeaf::new(0xc0, vec![0x51])?, TapLeaf::tapscript(vec![0x51]));
+        assert_eq!(TapLeaf::new(0xfa, vec![])?.version, 0xfa);
+        assert_eq!(TapLeaf::new(0xc1, vec![0x51]), Err(Errors::InvalidLeafVersion(0xc1)));
+        Ok(())
+    }
+}
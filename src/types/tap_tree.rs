@@ -0,0 +1,48 @@
+use crate::types::TapLeaf;
+
+/// Represents a taproot script tree, every branch has exactly two children.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TapTree {
+    Leaf(TapLeaf),
+    Branch(Box<TapTree>, Box<TapTree>)
+}
+
+impl TapTree {
+    /// Creates a branch of the two subtrees.
+    pub fn branch(left: TapTree, right: TapTree) -> Self {
+        TapTree::Branch(Box::new(left), Box::new(right))
+    }
+    /// Returns the leaves from left to right.
+    pub fn leaves(&self) -> Vec<&TapLeaf> {
+        match self {
+            TapTree::Leaf(leaf) => vec![leaf],
+            TapTree::Branch(left, right) => {
+                let mut leaves = left.leaves();
+                leaves.extend(right.leaves());
+                leaves
+            }
+        }
+    }
+    /// Returns the length of the longest path from the root to a leaf.
+    pub fn depth(&self) -> usize {
+        match self {
+            TapTree::Leaf(_) => 0,
+            TapTree::Branch(left, right) => 1 + left.depth().max(right.depth())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_tree() {
+        let leaf = |op: u8| TapTree::Leaf(TapLeaf::tapscript(vec![op]));
+        let tree = TapTree::branch(leaf(0x51), TapTree::branch(leaf(0x52), leaf(0x53)));
+        let scripts: Vec<u8> = tree.leaves().iter().map(|leaf| leaf.script[0]).collect();
+        assert_eq!(scripts, vec![0x51, 0x52, 0x53]);
+        assert_eq!(tree.depth(), 2);
+        assert_eq!(leaf(0x51).depth(), 0);
+    }
+}
@@ -1,3 +1,5 @@
+// clippy lints fire inside the code generated by construct_uint!
+#![allow(clippy::manual_range_contains, clippy::assign_op_pattern)]
 use uint::construct_uint;
 use crate::types::U512;
 
@@ -13,6 +15,15 @@ impl U256 {
         let b = U512::from(b);
         a * b
     }
+    /// Swaps a and b if `choice` is true, without branching on `choice`.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let mask = 0u64.wrapping_sub(choice as u64);
+        for i in 0..4 {
+            let t = mask & (a.0[i] ^ b.0[i]);
+            a.0[i] ^= t;
+            b.0[i] ^= t;
+        }
+    }
 }
 
 impl std::convert::From<U512> for U256 {
@@ -1,3 +1,5 @@
+// clippy lints fire inside the code generated by construct_uint!
+#![allow(clippy::manual_range_contains, clippy::assign_op_pattern)]
 use uint::construct_uint;
 use crate::types::U256;
 use std;
@@ -0,0 +1,8 @@
+#[derive(Debug,PartialEq,Clone,Copy)]
+/// Decides which of the two valid forms (r, s) and (r, n - s) of a signature verification accepts.
+pub enum VerificationPolicy {
+    /// Both forms are accepted, as plain ECDSA does
+    Lax,
+    /// Only s <= n/2 (low-S, BIP62/BIP146) is accepted, which removes the malleability
+    Strict
+}
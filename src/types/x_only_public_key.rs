@@ -0,0 +1,64 @@
+use crate::types::{U256, Point, ECpoint, FieldElement, EllipticCurve, Errors};
+use crate::ec_math::lift_x;
+
+/// Represents a BIP340 public key, only the x coordinate is kept and y is always even.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct XOnlyPublicKey {
+    point: Point
+}
+
+impl XOnlyPublicKey {
+    /// Returns the key of `point`, a point with odd y is replaced by its negation.
+    pub fn from_point(point: &ECpoint, curve: &EllipticCurve) -> Result<Self, Errors> {
+        match point {
+            ECpoint::Infinity => Err(Errors::PointAtInfinity),
+            ECpoint::OnCurve(point) => {
+                let y = match point.y.bit(0) {
+                    true => (-FieldElement::new(point.y, curve.p)).value(),
+                    false => point.y
+                };
+                Ok(XOnlyPublicKey { point: Point::new(point.x, y) })
+            }
+        }
+    }
+    /// Parses the 32 byte x coordinate, it must be below p and belong to a point of the curve.
+    pub fn from_bytes(bytes: &[u8; 32], curve: &EllipticCurve) -> Result<Self, Errors> {
+        let point = lift_x(U256::from_big_endian(bytes), false, curve)?;
+        Ok(XOnlyPublicKey { point: point.to_finite_point() })
+    }
+    /// Returns the 32 byte big endian x coordinate.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        self.point.x.to_big_endian(&mut bytes);
+        bytes
+    }
+    /// Returns the x coordinate.
+    pub fn x(&self) -> U256 {
+        self.point.x
+    }
+    /// Returns the point with even y.
+    pub fn to_point(&self) -> ECpoint {
+        ECpoint::OnCurve(self.point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ec_math::point_neg;
+
+    #[test]
+    fn test_x_only_public_key() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let g = ECpoint::OnCurve(Point::from(secp256k1.g));
+        let key = XOnlyPublicKey::from_point(&g, &secp256k1)?;
+        // G and -G have the same x only key, the one with even y
+        assert_eq!(XOnlyPublicKey::from_point(&point_neg(&g.into(), &secp256k1)?, &secp256k1)?, key);
+        assert_eq!(key.to_point(), g);
+        assert_eq!(XOnlyPublicKey::from_bytes(&key.to_bytes(), &secp256k1)?, key);
+        assert_eq!(key.x(), secp256k1.g.0);
+        assert_eq!(XOnlyPublicKey::from_point(&ECpoint::Infinity, &secp256k1), Err(Errors::PointAtInfinity));
+        assert_eq!(XOnlyPublicKey::from_bytes(&[0u8; 32], &secp256k1), Err(Errors::XNotOnCurve(U256::zero())));
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use ring::digest::{digest, SHA256};
+
+/// Returns the Base58Check encoding of `payload` (which starts with the version byte):
+/// Base58 of the payload followed by the first 4 bytes of its double SHA-256.
+pub fn base58check_encode(payload: &[u8]) -> String {
+    let checksum = digest(&SHA256, digest(&SHA256, payload).as_ref());
+    let mut data = payload.to_vec();
+    data.extend_from_slice(&checksum.as_ref()[..4]);
+    bs58::encode(data).into_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base58check_encode() {
+        // P2PKH address of private key 1 (compressed)
+        let payload = hex::decode("00751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        assert_eq!(base58check_encode(&payload), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+        // leading zero bytes become '1'
+        assert_eq!(base58check_encode(&[0, 0]), "112edB6q");
+    }
+}
@@ -0,0 +1,26 @@
+use crate::utils::compact_size;
+
+/// Returns the bytes Bitcoin's signmessage hashes: the "Bitcoin Signed Message:\n" magic
+/// and the message, each preceded by its length as a compact size integer.
+pub fn bitcoin_message(message: &[u8]) -> Vec<u8> {
+    let magic = b"Bitcoin Signed Message:\n";
+    let mut data = compact_size(magic.len() as u64);
+    data.extend_from_slice(magic);
+    data.extend(compact_size(message.len() as u64));
+    data.extend_from_slice(message);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitcoin_message() {
+        assert_eq!(bitcoin_message(b"abc"), b"\x18Bitcoin Signed Message:\n\x03abc".to_vec());
+        let long = vec![b'a'; 300];
+        let data = bitcoin_message(&long);
+        assert_eq!(data[25..28], [0xfd, 0x2c, 0x01]);
+        assert_eq!(data.len(), 25 + 3 + 300);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::types::{Scalar, ECpoint, DleqProof, EllipticCurve, Errors};
+use crate::ec_math::{scalar_mult_ladder, scalar_mult_base};
+use crate::utils::tagged_hash;
+
+/// Proves that A = x * G and B = x * H have the same discrete logarithm x without revealing it.
+/// The nonce q is derived from x, `aux_rand` and the statement, Q1 = q * G, Q2 = q * H,
+/// e = H("DLEQ", A || H || B || Q1 || Q2) and s = q + e * x. Returns ZeroPrivateKey if x is zero.
+pub fn dleq_prove(x: Scalar, h: &ECpoint, aux_rand: &[u8; 32], curve: &EllipticCurve) -> Result<DleqProof, Errors> {
+    if x.is_zero() {
+        return Err(Errors::ZeroPrivateKey);
+    }
+    if *h == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let (a, b) = (scalar_mult_base(x.value(), curve)?, scalar_mult_ladder(x.value(), &(*h).into(), curve)?);
+    let (a_bytes, h_bytes, b_bytes) = (a.to_sec1(true, curve), h.to_sec1(true, curve), b.to_sec1(true, curve));
+    let q = Scalar::from_bytes_reduced(&tagged_hash("DLEQ/nonce", &[&x.to_bytes(), aux_rand, &a_bytes, &h_bytes, &b_bytes]), curve);
+    if q.is_zero() {
+        return Err(Errors::ZeroNonce);
+    }
+    let (q1, q2) = (scalar_mult_base(q.value(), curve)?, scalar_mult_ladder(q.value(), &(*h).into(), curve)?);
+    let e = Scalar::from_bytes_reduced(&tagged_hash("DLEQ", &[&a_bytes, &h_bytes, &b_bytes, &q1.to_sec1(true, curve), &q2.to_sec1(true, curve)]), curve);
+    Ok(DleqProof::new(e, q + e * x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::ec_math::scalar_mult;
+    use crate::utils::dleq_verify;
+
+    #[test]
+    fn test_dleq_prove() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let x = Scalar::new(U256::from(1234567), &secp256k1);
+        let h = scalar_mult_base(U256::from(42), &secp256k1)?;
+        let proof = dleq_prove(x, &h, &[0u8; 32], &secp256k1)?;
+        assert_eq!(dleq_prove(x, &h, &[0u8; 32], &secp256k1)?, proof);
+        assert_ne!(dleq_prove(x, &h, &[1u8; 32], &secp256k1)?, proof);
+        let a = scalar_mult_base(x.value(), &secp256k1)?;
+        let b = scalar_mult(x.value(), &h.into(), &secp256k1)?;
+        assert!(dleq_verify(&a, &h, &b, &proof, &secp256k1)?);
+        assert_eq!(dleq_prove(x, &ECpoint::Infinity, &[0u8; 32], &secp256k1), Err(Errors::PointAtInfinity));
+        assert_eq!(dleq_prove(Scalar::zero(&secp256k1), &h, &[0u8; 32], &secp256k1), Err(Errors::ZeroPrivateKey));
+        Ok(())
+    }
+}
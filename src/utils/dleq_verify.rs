@@ -0,0 +1,46 @@
+use crate::types::{Scalar, Point, Points, ECpoint, DleqProof, EllipticCurve, Errors};
+use crate::ec_math::multi_scalar_mult;
+use crate::utils::tagged_hash;
+
+/// Verifies a proof that A = x * G and B = x * H for the same x: recomputes Q1 = s * G - e * A and
+/// Q2 = s * H - e * B and checks the challenge e. Points at infinity never verify.
+pub fn dleq_verify(a: &ECpoint, h: &ECpoint, b: &ECpoint, proof: &DleqProof, curve: &EllipticCurve) -> Result<bool, Errors> {
+    if [a, h, b].iter().any(|point| **point == ECpoint::Infinity) {
+        return Ok(false);
+    }
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    let minus_e = (-proof.e).value();
+    let q1 = multi_scalar_mult(&[(proof.s.value(), G), (minus_e, (*a).into())], curve)?;
+    let q2 = multi_scalar_mult(&[(proof.s.value(), (*h).into()), (minus_e, (*b).into())], curve)?;
+    let e = Scalar::from_bytes_reduced(&tagged_hash("DLEQ", &[&a.to_sec1(true, curve), &h.to_sec1(true, curve), &b.to_sec1(true, curve),
+        &q1.to_sec1(true, curve), &q2.to_sec1(true, curve)]), curve);
+    Ok(e == proof.e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::ec_math::{scalar_mult, scalar_mult_base};
+    use crate::utils::dleq_prove;
+
+    #[test]
+    fn test_dleq_verify() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let x = Scalar::new(U256::from(1234567), &secp256k1);
+        let h = scalar_mult_base(U256::from(42), &secp256k1)?;
+        let a = scalar_mult_base(x.value(), &secp256k1)?;
+        let b = scalar_mult(x.value(), &h.into(), &secp256k1)?;
+        let proof = dleq_prove(x, &h, &[7u8; 32], &secp256k1)?;
+        assert!(dleq_verify(&a, &h, &b, &proof, &secp256k1)?);
+        // B with another logarithm, swapped points and a changed response fail
+        let other = scalar_mult(U256::from(1234568), &h.into(), &secp256k1)?;
+        assert!(!dleq_verify(&a, &h, &other, &proof, &secp256k1)?);
+        assert!(!dleq_verify(&b, &h, &a, &proof, &secp256k1)?);
+        let forged = DleqProof::new(proof.e, proof.s + Scalar::one(&secp256k1));
+        assert!(!dleq_verify(&a, &h, &b, &forged, &secp256k1)?);
+        assert!(!dleq_verify(&a, &ECpoint::Infinity, &b, &proof, &secp256k1)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::types::{Scalar, ECpoint, EcdsaPreSignature, Signature, EllipticCurve, Errors};
+
+/// Completes an ECDSA pre-signature with the secret t of its adaptor point into the signature (r, s' / t)
+/// with r = x(R) mod n. s is returned in its low-S form.
+pub fn ecdsa_adaptor_complete(pre_signature: &EcdsaPreSignature, adaptor_secret: Scalar, curve: &EllipticCurve) -> Result<Signature, Errors> {
+    if pre_signature.r == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let r = Scalar::new(pre_signature.r.to_finite_point().x, curve);
+    let s = pre_signature.s * adaptor_secret.inverse()?;
+    Ok(Signature::new(r, s).normalize_s(curve))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, SignatureVerification, MessageDigest};
+    use crate::ec_math::scalar_mult_base;
+    use crate::utils::{ecdsa_adaptor_sign, make_keypair, verify_signature};
+
+    #[test]
+    fn test_ecdsa_adaptor_complete() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let (a, A) = make_keypair(&secp256k1)?;
+        let t = Scalar::new(U256::from(13), &secp256k1);
+        #[allow(non_snake_case)]
+        let T = scalar_mult_base(t.value(), &secp256k1)?;
+        let pre_signature = ecdsa_adaptor_sign(a, b"swap", MessageDigest::Sha256, &T, &[0u8; 32], &secp256k1)?;
+        let signature = ecdsa_adaptor_complete(&pre_signature, t, &secp256k1)?;
+        assert!(signature.is_low_s(&secp256k1));
+        assert_eq!(verify_signature(A, b"swap", &signature, MessageDigest::Sha256, &secp256k1)?, SignatureVerification::Successful);
+        let wrong = ecdsa_adaptor_complete(&pre_signature, t + Scalar::one(&secp256k1), &secp256k1)?;
+        assert_eq!(verify_signature(A, b"swap", &wrong, MessageDigest::Sha256, &secp256k1)?, SignatureVerification::Failed);
+        assert!(ecdsa_adaptor_complete(&pre_signature, Scalar::zero(&secp256k1), &secp256k1).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::types::{Scalar, ECpoint, EcdsaPreSignature, Signature, EllipticCurve, Errors};
+use crate::ec_math::{point_neg, scalar_mult_base};
+
+/// Recovers the secret t of the adaptor point T from a pre-signature and the signature completed from it:
+/// t = s' / s, negated if the signature was normalized to low-S. Returns AdaptorMismatch if the signature
+/// was not completed from this pre-signature with the secret of T.
+pub fn ecdsa_adaptor_extract(pre_signature: &EcdsaPreSignature, signature: &Signature, adaptor_point: &ECpoint, curve: &EllipticCurve) -> Result<Scalar, Errors> {
+    if pre_signature.r == ECpoint::Infinity || *adaptor_point == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let s = signature.s;
+    if signature.r != Scalar::new(pre_signature.r.to_finite_point().x, curve) || s.is_zero() {
+        return Err(Errors::AdaptorMismatch);
+    }
+    let t = pre_signature.s * s.inverse_vartime()?;
+    let point = scalar_mult_base(t.value(), curve)?;
+    match (point == *adaptor_point, point == point_neg(&(*adaptor_point).into(), curve)?) {
+        (true, _) => Ok(t),
+        (false, true) => Ok(-t),
+        (false, false) => Err(Errors::AdaptorMismatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, MessageDigest};
+    use crate::traits::GenRandU256;
+    use crate::utils::{ecdsa_adaptor_sign, ecdsa_adaptor_complete, make_keypair, sign_message};
+
+    #[test]
+    fn test_ecdsa_adaptor_extract() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let (a, _) = make_keypair(&secp256k1)?;
+        let mut rng = rand::thread_rng();
+        // the completed signature is normalized to low-S about every other time, t comes back either way
+        for i in 0..8u8 {
+            let t = Scalar::new(rng.gen_u256_range(&U256::one(), &secp256k1.n), &secp256k1);
+            #[allow(non_snake_case)]
+            let T = scalar_mult_base(t.value(), &secp256k1)?;
+            let pre_signature = ecdsa_adaptor_sign(a, b"swap", MessageDigest::Sha256, &T, &[i; 32], &secp256k1)?;
+            let signature = ecdsa_adaptor_complete(&pre_signature, t, &secp256k1)?;
+            assert_eq!(ecdsa_adaptor_extract(&pre_signature, &signature, &T, &secp256k1)?, t);
+            let high_s = Signature::new(signature.r, -signature.s);
+            assert_eq!(ecdsa_adaptor_extract(&pre_signature, &high_s, &T, &secp256k1)?, t);
+            let wrong = ecdsa_adaptor_complete(&pre_signature, t + Scalar::one(&secp256k1), &secp256k1)?;
+            assert_eq!(ecdsa_adaptor_extract(&pre_signature, &wrong, &T, &secp256k1), Err(Errors::AdaptorMismatch));
+            let plain = sign_message(a, b"swap", MessageDigest::Sha256, &secp256k1)?;
+            assert_eq!(ecdsa_adaptor_extract(&pre_signature, &plain, &T, &secp256k1), Err(Errors::AdaptorMismatch));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::types::{Scalar, ECpoint, EcdsaPreSignature, EllipticCurve, Errors, MessageDigest};
+use crate::utils::{hash_message, tagged_hash, dleq_prove};
+use crate::ec_math::{scalar_mult_ladder, scalar_mult_base};
+
+/// Creates an ECDSA pre-signature of `message` bound to the adaptor point T = t * G. The nonce point of the final
+/// signature is R = k * T, so s' = k^-1 * (z + r * d) with r = x(R) only becomes a valid signature once divided by t.
+/// R_a = k * G and a DLEQ proof that R and R_a share k let anyone check this without knowing t.
+/// The nonce is derived from the key, T, the hash of the message and `aux_rand`.
+pub fn ecdsa_adaptor_sign(private_key: Scalar, message: &[u8], digest: MessageDigest, adaptor_point: &ECpoint, aux_rand: &[u8; 32], curve: &EllipticCurve) -> Result<EcdsaPreSignature, Errors> {
+    if private_key.is_zero() {
+        return Err(Errors::ZeroPrivateKey);
+    }
+    if *adaptor_point == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let z = Scalar::new(hash_message(message, digest, curve.n.bits())?, curve);
+    let mut masked_key = private_key.to_bytes();
+    for (byte, mask) in masked_key.iter_mut().zip(tagged_hash("ECDSAadaptor/aux", &[aux_rand]).iter()) {
+        *byte ^= mask;
+    }
+    let k = Scalar::from_bytes_reduced(&tagged_hash("ECDSAadaptor/nonce", &[&masked_key, &adaptor_point.to_sec1(true, curve), &z.to_bytes()]), curve);
+    if k.is_zero() {
+        return Err(Errors::ZeroNonce);
+    }
+    let (r, r_a) = (scalar_mult_ladder(k.value(), &(*adaptor_point).into(), curve)?, scalar_mult_base(k.value(), curve)?);
+    let r_scalar = Scalar::new(r.to_finite_point().x, curve);
+    if r_scalar.is_zero() {
+        return Err(Errors::ZeroNonce);
+    }
+    let s = k.inverse()? * (z + r_scalar * private_key);
+    let proof = dleq_prove(k, adaptor_point, aux_rand, curve)?;
+    Ok(EcdsaPreSignature {r, r_a, s, proof})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, SignatureVerification};
+    use crate::utils::{ecdsa_adaptor_verify, ecdsa_adaptor_complete, make_keypair, verify_signature};
+
+    #[test]
+    fn test_ecdsa_adaptor_sign() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let (a, A) = make_keypair(&secp256k1)?;
+        let t = Scalar::new(U256::from(11), &secp256k1);
+        #[allow(non_snake_case)]
+        let T = scalar_mult_base(t.value(), &secp256k1)?;
+        let pre_signature = ecdsa_adaptor_sign(a, b"swap", MessageDigest::Sha256, &T, &[0u8; 32], &secp256k1)?;
+        assert_eq!(ecdsa_adaptor_sign(a, b"swap", MessageDigest::Sha256, &T, &[0u8; 32], &secp256k1)?, pre_signature);
+        assert_ne!(ecdsa_adaptor_sign(a, b"swap", MessageDigest::Sha256, &T, &[1u8; 32], &secp256k1)?, pre_signature);
+        assert_eq!(ecdsa_adaptor_verify(A, b"swap", MessageDigest::Sha256, &T, &pre_signature, &secp256k1)?, SignatureVerification::Successful);
+        let signature = ecdsa_adaptor_complete(&pre_signature, t, &secp256k1)?;
+        assert_eq!(verify_signature(A, b"swap", &signature, MessageDigest::Sha256, &secp256k1)?, SignatureVerification::Successful);
+        // without t the pre-signature is no signature
+        let unadapted = ecdsa_adaptor_complete(&pre_signature, Scalar::one(&secp256k1), &secp256k1)?;
+        assert_eq!(verify_signature(A, b"swap", &unadapted, MessageDigest::Sha256, &secp256k1)?, SignatureVerification::Failed);
+        assert_eq!(ecdsa_adaptor_sign(Scalar::zero(&secp256k1), b"swap", MessageDigest::Sha256, &T, &[0u8; 32], &secp256k1), Err(Errors::ZeroPrivateKey));
+        assert_eq!(ecdsa_adaptor_sign(a, b"swap", MessageDigest::Sha256, &ECpoint::Infinity, &[0u8; 32], &secp256k1), Err(Errors::PointAtInfinity));
+        Ok(())
+    }
+}
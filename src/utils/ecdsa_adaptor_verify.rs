@@ -0,0 +1,60 @@
+use crate::types::{Scalar, Point, Points, ECpoint, EcdsaPreSignature, SignatureVerification, EllipticCurve, Errors, MessageDigest};
+use crate::utils::{hash_message, dleq_verify};
+use crate::ec_math::multi_scalar_mult;
+
+/// Verifies an ECDSA pre-signature for the adaptor point T: the DLEQ proof must show that R = k * T and
+/// R_a = k * G share k, and (z * G + r * P) / s' must be R_a with r = x(R). If both hold, dividing s'
+/// by the secret of T yields a valid signature.
+pub fn ecdsa_adaptor_verify(public_key: ECpoint, message: &[u8], digest: MessageDigest, adaptor_point: &ECpoint, pre_signature: &EcdsaPreSignature, curve: &EllipticCurve) -> Result<SignatureVerification, Errors> {
+    if public_key == ECpoint::Infinity || pre_signature.s.is_zero() {
+        return Ok(SignatureVerification::Failed);
+    }
+    if !dleq_verify(&pre_signature.r_a, adaptor_point, &pre_signature.r, &pre_signature.proof, curve)? {
+        return Ok(SignatureVerification::Failed);
+    }
+    let r = Scalar::new(pre_signature.r.to_finite_point().x, curve);
+    if r.is_zero() {
+        return Ok(SignatureVerification::Failed);
+    }
+    let z = Scalar::new(hash_message(message, digest, curve.n.bits())?, curve);
+    let s_inv = pre_signature.s.inverse_vartime()?;
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    let r_a = multi_scalar_mult(&[((s_inv * z).value(), G), ((s_inv * r).value(), public_key.into())], curve)?;
+    match r_a == pre_signature.r_a {
+        true => Ok(SignatureVerification::Successful),
+        false => Ok(SignatureVerification::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, DleqProof};
+    use crate::ec_math::scalar_mult_base;
+    use crate::utils::{ecdsa_adaptor_sign, make_keypair};
+
+    #[test]
+    fn test_ecdsa_adaptor_verify() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let (a, A) = make_keypair(&secp256k1)?;
+        #[allow(non_snake_case)]
+        let T = scalar_mult_base(U256::from(11), &secp256k1)?;
+        let pre_signature = ecdsa_adaptor_sign(a, b"swap", MessageDigest::Sha256, &T, &[0u8; 32], &secp256k1)?;
+        assert_eq!(ecdsa_adaptor_verify(A, b"swap", MessageDigest::Sha256, &T, &pre_signature, &secp256k1)?, SignatureVerification::Successful);
+        assert_eq!(ecdsa_adaptor_verify(A, b"other", MessageDigest::Sha256, &T, &pre_signature, &secp256k1)?, SignatureVerification::Failed);
+        assert_eq!(ecdsa_adaptor_verify(A, b"swap", MessageDigest::Sha512, &T, &pre_signature, &secp256k1)?, SignatureVerification::Failed);
+        let other_point = scalar_mult_base(U256::from(12), &secp256k1)?;
+        assert_eq!(ecdsa_adaptor_verify(A, b"swap", MessageDigest::Sha256, &other_point, &pre_signature, &secp256k1)?, SignatureVerification::Failed);
+        #[allow(non_snake_case)]
+        let (_, B) = make_keypair(&secp256k1)?;
+        assert_eq!(ecdsa_adaptor_verify(B, b"swap", MessageDigest::Sha256, &T, &pre_signature, &secp256k1)?, SignatureVerification::Failed);
+        // R has to come with a proof that it is k * T
+        let forged = EcdsaPreSignature {proof: DleqProof::new(pre_signature.proof.e, pre_signature.proof.s + Scalar::one(&secp256k1)), ..pre_signature};
+        assert_eq!(ecdsa_adaptor_verify(A, b"swap", MessageDigest::Sha256, &T, &forged, &secp256k1)?, SignatureVerification::Failed);
+        let forged = EcdsaPreSignature {s: pre_signature.s + Scalar::one(&secp256k1), ..pre_signature};
+        assert_eq!(ecdsa_adaptor_verify(A, b"swap", MessageDigest::Sha256, &T, &forged, &secp256k1)?, SignatureVerification::Failed);
+        Ok(())
+    }
+}
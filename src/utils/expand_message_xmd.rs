@@ -0,0 +1,49 @@
+use ring::digest::{Context, SHA256};
+use crate::types::Errors;
+
+/// Expands `message` into `length` uniformly random looking bytes with SHA-256 (RFC 9380 expand_message_xmd),
+/// the domain separation tag `dst` keeps different uses apart. At most 255 blocks of 32 bytes can be produced.
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], length: usize) -> Result<Vec<u8>, Errors> {
+    let blocks = length.div_ceil(32);
+    if blocks > 255 || length > 0xffff {
+        return Err(Errors::InvalidExpandLength(length));
+    }
+    if dst.len() > 255 {
+        return Err(Errors::InvalidExpandLength(dst.len()));
+    }
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+    let hash = |parts: &[&[u8]]| {
+        let mut context = Context::new(&SHA256);
+        for part in parts.iter() {
+            context.update(part);
+        }
+        context.finish()
+    };
+    // b_0 = H(Z_pad || msg || l_i_b_str || 0 || DST_prime), the zero pad is one SHA-256 block
+    let b_0 = hash(&[&[0u8; 64], message, &(length as u16).to_be_bytes(), &[0], &dst_prime]);
+    let mut b_i = hash(&[b_0.as_ref(), &[1], &dst_prime]);
+    let mut output = b_i.as_ref().to_vec();
+    for i in 2..=blocks {
+        let mixed: Vec<u8> = b_0.as_ref().iter().zip(b_i.as_ref().iter()).map(|(a, b)| a ^ b).collect();
+        b_i = hash(&[&mixed, &[i as u8], &dst_prime]);
+        output.extend_from_slice(b_i.as_ref());
+    }
+    output.truncate(length);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_message_xmd() -> Result<(), Errors> {
+        // RFC 9380 appendix K.1 (expand_message_xmd with SHA-256)
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(hex::encode(expand_message_xmd(b"", dst, 0x20)?), "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235");
+        assert_eq!(hex::encode(expand_message_xmd(b"abc", dst, 0x20)?), "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615");
+        assert_eq!(expand_message_xmd(b"abc", dst, 48)?.len(), 48);
+        assert_eq!(expand_message_xmd(b"abc", dst, 256 * 32), Err(Errors::InvalidExpandLength(256 * 32)));
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::types::{Scalar, ECpoint, FrostCommitment, FrostSignature, EllipticCurve, Errors};
+use crate::utils::frost_session;
+
+/// Combines the signature shares of all signers into the group signature (R, z) (RFC 9591 aggregate),
+/// R is the group commitment of the session and z the sum of the shares. The shares are not checked here,
+/// if the result does not verify `frost_verify_share` tells which one is bad. There must be one share per commitment.
+pub fn frost_aggregate(shares: &[Scalar], commitments: &[FrostCommitment], group_public_key: &ECpoint, message: &[u8], curve: &EllipticCurve) -> Result<FrostSignature, Errors> {
+    if shares.len() != commitments.len() {
+        return Err(Errors::SignatureShareCountMismatch(shares.len(), commitments.len()));
+    }
+    let session = frost_session(group_public_key, commitments, message, curve)?;
+    let z = shares.iter().fold(Scalar::zero(curve), |z, share| z + *share);
+    Ok(FrostSignature::new(session.group_commitment, z))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::frost_test_vectors::Rfc9591Vectors;
+
+    #[test]
+    fn test_frost_aggregate() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let vectors = Rfc9591Vectors::load();
+        let group_public_key = vectors.point("group_public_key", &secp256k1)?;
+        let message = vectors.bytes("message");
+        let commitments = vectors.commitments(&secp256k1)?;
+        let shares: Vec<_> = vectors.participants().iter().map(|i| vectors.scalar(&format!("sig_share_{}", i), &secp256k1)).collect();
+        let signature = frost_aggregate(&shares, &commitments, &group_public_key, &message, &secp256k1)?;
+        assert_eq!(hex::encode(signature.to_bytes(&secp256k1)?), vectors.hex("sig"));
+        assert_eq!(frost_aggregate(&shares[..1], &commitments, &group_public_key, &message, &secp256k1), Err(Errors::SignatureShareCountMismatch(1, 2)));
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::types::{Scalar, FrostKeyPackage, FrostNonces, FrostCommitment, EllipticCurve, Errors};
+use crate::ec_math::scalar_mult_base;
+use crate::utils::frost_nonce_generate;
+use crate::traits::GenRandU256;
+
+/// First round of FROST signing (RFC 9591 commit): draws the hiding and binding nonces of the participant.
+/// The nonces are kept for `frost_sign`, the commitment is sent to the coordinator.
+pub fn frost_commit<R: GenRandU256>(rng: &mut R, key_package: &FrostKeyPackage, curve: &EllipticCurve) -> Result<(FrostNonces, FrostCommitment), Errors> {
+    let mut nonce = || -> Result<Scalar, Errors> {
+        let mut random_bytes = [0u8; 32];
+        rng.gen_u256().to_big_endian(&mut random_bytes);
+        frost_nonce_generate(&random_bytes, &key_package.secret_share, curve)
+    };
+    let (hiding, binding) = (nonce()?, nonce()?);
+    let commitment = FrostCommitment {
+        identifier: key_package.identifier,
+        hiding: scalar_mult_base(hiding.value(), curve)?,
+        binding: scalar_mult_base(binding.value(), curve)?
+    };
+    Ok((FrostNonces {hiding, binding}, commitment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::frost_test_vectors::{MockRng, Rfc9591Vectors};
+
+    #[test]
+    fn test_frost_commit() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let vectors = Rfc9591Vectors::load();
+        for i in vectors.participants() {
+            let key_package = vectors.key_package(i, &secp256k1)?;
+            let mut rng = MockRng::new(vec![vectors.u256(&format!("hiding_nonce_randomness_{}", i)),
+                                            vectors.u256(&format!("binding_nonce_randomness_{}", i))]);
+            let (nonces, commitment) = frost_commit(&mut rng, &key_package, &secp256k1)?;
+            assert_eq!(hex::encode(nonces.hiding.to_bytes()), vectors.hex(&format!("hiding_nonce_{}", i)));
+            assert_eq!(hex::encode(nonces.binding.to_bytes()), vectors.hex(&format!("binding_nonce_{}", i)));
+            assert_eq!(hex::encode(commitment.hiding.to_sec1(true, &secp256k1)), vectors.hex(&format!("hiding_nonce_commitment_{}", i)));
+            assert_eq!(hex::encode(commitment.binding.to_sec1(true, &secp256k1)), vectors.hex(&format!("binding_nonce_commitment_{}", i)));
+            assert_eq!(commitment.identifier, key_package.identifier);
+        }
+        Ok(())
+    }
+}
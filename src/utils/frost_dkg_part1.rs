@@ -0,0 +1,52 @@
+use crate::types::{U256, Scalar, FrostDkgSecret, FrostDkgPackage, EllipticCurve, Errors};
+use crate::ec_math::scalar_mult_base;
+use crate::utils::frost_hash_to_scalar;
+use crate::traits::GenRandU256;
+
+/// First round of the FROST distributed key generation (Pedersen DKG with Feldman commitments).
+/// Draws a random polynomial of degree min_signers - 1 and returns it as the secret to keep for the next rounds,
+/// together with the package to broadcast: the commitments a_j * G and a Schnorr proof of knowledge of a_0
+/// (R = k * G, mu = k + a_0 * c with c = H("dkg", identifier || a_0 * G || R)) against rogue key attacks.
+pub fn frost_dkg_part1<R: GenRandU256>(rng: &mut R, identifier: Scalar, min_signers: usize, max_signers: usize, curve: &EllipticCurve) -> Result<(FrostDkgSecret, FrostDkgPackage), Errors> {
+    if min_signers < 2 || min_signers > max_signers {
+        return Err(Errors::InvalidThreshold(min_signers, max_signers));
+    }
+    if identifier.is_zero() {
+        return Err(Errors::ZeroIdentifier);
+    }
+    let mut coefficients = Vec::with_capacity(min_signers);
+    let mut commitments = Vec::with_capacity(min_signers);
+    for _ in 0..min_signers {
+        let coefficient = Scalar::new(rng.gen_u256_range(&U256::one(), &curve.n), curve);
+        commitments.push(scalar_mult_base(coefficient.value(), curve)?);
+        coefficients.push(coefficient);
+    }
+    let k = Scalar::new(rng.gen_u256_range(&U256::one(), &curve.n), curve);
+    let proof_r = scalar_mult_base(k.value(), curve)?;
+    let c = frost_hash_to_scalar("dkg", &[&identifier.to_bytes(), &commitments[0].to_sec1(true, curve), &proof_r.to_sec1(true, curve)], curve)?;
+    let proof_mu = k + coefficients[0] * c;
+    Ok((FrostDkgSecret {identifier, coefficients}, FrostDkgPackage {identifier, commitments, proof_r, proof_mu}))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frost_dkg_part1() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let mut rng = rand::thread_rng();
+        let identifier = Scalar::new(U256::from(2), &secp256k1);
+        let (secret, package) = frost_dkg_part1(&mut rng, identifier, 3, 5, &secp256k1)?;
+        assert_eq!(secret.coefficients.len(), 3);
+        assert_eq!(package.commitments.len(), 3);
+        assert_eq!(package.identifier, identifier);
+        for (coefficient, commitment) in secret.coefficients.iter().zip(package.commitments.iter()) {
+            assert_eq!(scalar_mult_base(coefficient.value(), &secp256k1)?, *commitment);
+        }
+        assert_eq!(frost_dkg_part1(&mut rng, identifier, 1, 5, &secp256k1), Err(Errors::InvalidThreshold(1, 5)));
+        assert_eq!(frost_dkg_part1(&mut rng, identifier, 6, 5, &secp256k1), Err(Errors::InvalidThreshold(6, 5)));
+        assert_eq!(frost_dkg_part1(&mut rng, Scalar::zero(&secp256k1), 3, 5, &secp256k1), Err(Errors::ZeroIdentifier));
+        Ok(())
+    }
+}
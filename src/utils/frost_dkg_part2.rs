@@ -0,0 +1,78 @@
+use crate::types::{Scalar, FrostDkgSecret, FrostDkgPackage, EllipticCurve, Errors};
+use crate::ec_math::{point_add, scalar_mult, scalar_mult_base};
+use crate::group_math::polynomial_evaluate;
+use crate::utils::frost_hash_to_scalar;
+
+/// Second round of the FROST distributed key generation. Checks the round one packages of the other
+/// participants and returns the share f(j) of our polynomial for each of them as (recipient, share),
+/// every share has to be sent to its recipient over a private channel.
+/// Returns InvalidProofOfKnowledge for a bad proof, InvalidCommitmentCount if a package commits to a polynomial
+/// of another degree, InvalidThreshold if there are too few participants and DuplicateParticipant for a repeated identifier.
+pub fn frost_dkg_part2(secret: &FrostDkgSecret, packages: &[FrostDkgPackage], curve: &EllipticCurve) -> Result<Vec<(Scalar, Scalar)>, Errors> {
+    let min_signers = secret.coefficients.len();
+    if packages.len() + 1 < min_signers {
+        return Err(Errors::InvalidThreshold(min_signers, packages.len() + 1));
+    }
+    let mut identifiers = vec![secret.identifier];
+    for package in packages.iter() {
+        if identifiers.contains(&package.identifier) {
+            return Err(Errors::DuplicateParticipant(package.identifier.value()));
+        }
+        identifiers.push(package.identifier);
+        if package.commitments.len() != min_signers {
+            return Err(Errors::InvalidCommitmentCount(package.identifier.value(), package.commitments.len(), min_signers));
+        }
+        // R = mu * G - c * a_0 * G
+        let c = frost_hash_to_scalar("dkg", &[&package.identifier.to_bytes(), &package.commitments[0].to_sec1(true, curve),
+            &package.proof_r.to_sec1(true, curve)], curve)?;
+        let c_a0 = scalar_mult((-c).value(), &package.commitments[0].into(), curve)?;
+        let r = point_add(&scalar_mult_base(package.proof_mu.value(), curve)?.into(), &c_a0.into(), curve)?;
+        if r != package.proof_r {
+            return Err(Errors::InvalidProofOfKnowledge(package.identifier.value()));
+        }
+    }
+    let coefficients: Vec<_> = secret.coefficients.iter().map(|coefficient| coefficient.value()).collect();
+    let mut shares = Vec::with_capacity(packages.len());
+    for package in packages.iter() {
+        let share = polynomial_evaluate(&coefficients, package.identifier.value(), curve.n)?;
+        shares.push((package.identifier, Scalar::new(share, curve)));
+    }
+    Ok(shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::utils::{frost_dkg_part1, frost_vss_verify};
+
+    #[test]
+    fn test_frost_dkg_part2() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let mut rng = rand::thread_rng();
+        let mut secrets = Vec::new();
+        let mut packages = Vec::new();
+        for i in 1..=3 {
+            let (secret, package) = frost_dkg_part1(&mut rng, Scalar::new(U256::from(i), &secp256k1), 2, 3, &secp256k1)?;
+            secrets.push(secret);
+            packages.push(package);
+        }
+        let shares = frost_dkg_part2(&secrets[0], &packages[1..], &secp256k1)?;
+        assert_eq!(shares.len(), 2);
+        for ((recipient, share), package) in shares.iter().zip(packages[1..].iter()) {
+            assert_eq!(*recipient, package.identifier);
+            assert!(frost_vss_verify(recipient, share, &packages[0].commitments, &secp256k1)?);
+        }
+        // a proof does not carry over to another identifier
+        let mut forged = packages[1].clone();
+        forged.identifier = Scalar::new(U256::from(4), &secp256k1);
+        assert_eq!(frost_dkg_part2(&secrets[0], &[forged], &secp256k1), Err(Errors::InvalidProofOfKnowledge(U256::from(4))));
+        assert_eq!(frost_dkg_part2(&secrets[0], &[packages[1].clone(), packages[1].clone()], &secp256k1), Err(Errors::DuplicateParticipant(U256::from(2))));
+        assert_eq!(frost_dkg_part2(&secrets[0], &[packages[0].clone()], &secp256k1), Err(Errors::DuplicateParticipant(U256::one())));
+        let mut short = packages[2].clone();
+        short.commitments.pop();
+        assert_eq!(frost_dkg_part2(&secrets[0], &[short], &secp256k1), Err(Errors::InvalidCommitmentCount(U256::from(3), 1, 2)));
+        assert_eq!(frost_dkg_part2(&secrets[0], &[], &secp256k1), Err(Errors::InvalidThreshold(2, 1)));
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::types::{Scalar, FrostDkgSecret, FrostDkgPackage, FrostKeyPackage, EllipticCurve, Errors};
+use crate::ec_math::{point_add, scalar_mult_base};
+use crate::group_math::polynomial_evaluate;
+use crate::utils::frost_vss_verify;
+
+/// Last round of the FROST distributed key generation, consumes the secret polynomial. Checks every share received
+/// as (sender, share) against the commitments of the sender and returns the key package: the secret share is the
+/// sum of all shares including our own, the group public key the sum of the a_0 * G commitments.
+/// Returns ParticipantNotInList if a share is missing or comes from an unknown sender, InvalidSecretShare for a bad one.
+pub fn frost_dkg_part3(secret: FrostDkgSecret, packages: &[FrostDkgPackage], received_shares: &[(Scalar, Scalar)], curve: &EllipticCurve) -> Result<FrostKeyPackage, Errors> {
+    if let Some((sender, _)) = received_shares.iter().find(|(sender, _)| !packages.iter().any(|package| package.identifier == *sender)) {
+        return Err(Errors::ParticipantNotInList(sender.value()));
+    }
+    let coefficients: Vec<_> = secret.coefficients.iter().map(|coefficient| coefficient.value()).collect();
+    let mut secret_share = Scalar::new(polynomial_evaluate(&coefficients, secret.identifier.value(), curve.n)?, curve);
+    let mut group_public_key = scalar_mult_base(coefficients[0], curve)?;
+    for package in packages.iter() {
+        let share = match received_shares.iter().find(|(sender, _)| *sender == package.identifier) {
+            Some((_, share)) => *share,
+            None => return Err(Errors::ParticipantNotInList(package.identifier.value()))
+        };
+        if !frost_vss_verify(&secret.identifier, &share, &package.commitments, curve)? {
+            return Err(Errors::InvalidSecretShare(package.identifier.value()));
+        }
+        secret_share = secret_share + share;
+        group_public_key = point_add(&group_public_key.into(), &package.commitments[0].into(), curve)?;
+    }
+    Ok(FrostKeyPackage {
+        identifier: secret.identifier,
+        secret_share,
+        public_share: scalar_mult_base(secret_share.value(), curve)?,
+        group_public_key
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::group_math::lagrange_coefficient;
+    use crate::utils::frost_test_vectors::DkgRounds;
+
+    #[test]
+    fn test_frost_dkg_part3() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let rounds = DkgRounds::run(&mut rand::thread_rng(), 2, 3, &secp256k1)?;
+        // a tampered share is caught
+        let mut tampered = rounds.received(0);
+        tampered[1].1 = tampered[1].1 + Scalar::one(&secp256k1);
+        let secret = FrostDkgSecret {identifier: rounds.secrets[0].identifier, coefficients: rounds.secrets[0].coefficients.clone()};
+        assert_eq!(frost_dkg_part3(secret, &rounds.others(0), &tampered, &secp256k1), Err(Errors::InvalidSecretShare(U256::from(3))));
+        let secret = FrostDkgSecret {identifier: rounds.secrets[0].identifier, coefficients: rounds.secrets[0].coefficients.clone()};
+        assert_eq!(frost_dkg_part3(secret, &rounds.others(0), &rounds.received(0)[..1], &secp256k1), Err(Errors::ParticipantNotInList(U256::from(3))));
+        let key_packages = rounds.key_packages(&secp256k1)?;
+        for key_package in key_packages.iter() {
+            assert_eq!(key_package.group_public_key, key_packages[0].group_public_key);
+        }
+        // any two shares interpolate the group secret
+        let xs = [U256::one(), U256::from(3)];
+        let mut group_secret = Scalar::zero(&secp256k1);
+        for key_package in [&key_packages[0], &key_packages[2]].iter() {
+            let lambda = Scalar::new(lagrange_coefficient(key_package.identifier.value(), &xs, secp256k1.n)?, &secp256k1);
+            group_secret = group_secret + lambda * key_package.secret_share;
+        }
+        assert_eq!(scalar_mult_base(group_secret.value(), &secp256k1)?, key_packages[0].group_public_key);
+        Ok(())
+    }
+}
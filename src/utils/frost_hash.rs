@@ -0,0 +1,28 @@
+use ring::digest::{Context, SHA256};
+use crate::utils::frost_hash_to_scalar::FROST_CONTEXT;
+
+/// Returns SHA-256 of the context string, the tag and the concatenated `data` (RFC 9591 H4 and H5
+/// for the tags "msg" and "com").
+pub fn frost_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let mut context = Context::new(&SHA256);
+    context.update(FROST_CONTEXT.as_bytes());
+    context.update(tag.as_bytes());
+    for part in data.iter() {
+        context.update(part);
+    }
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(context.finish().as_ref());
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::digest::digest;
+
+    #[test]
+    fn test_frost_hash() {
+        let expected = digest(&SHA256, b"FROST-secp256k1-SHA256-v1msgtest");
+        assert_eq!(frost_hash("msg", &[b"te", b"st"]).to_vec(), expected.as_ref().to_vec());
+    }
+}
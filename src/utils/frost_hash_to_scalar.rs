@@ -0,0 +1,31 @@
+use crate::types::{U256, U512, Scalar, EllipticCurve, Errors};
+use crate::utils::expand_message_xmd;
+
+/// Context string of the FROST(secp256k1, SHA-256) ciphersuite of RFC 9591.
+pub const FROST_CONTEXT: &str = "FROST-secp256k1-SHA256-v1";
+
+/// Hashes the concatenated `data` to a scalar (RFC 9591 H1, H2, H3 for the tags "rho", "chal" and "nonce"):
+/// 48 bytes of expand_message_xmd with the tag appended to the context string, reduced modulo n.
+pub fn frost_hash_to_scalar(tag: &str, data: &[&[u8]], curve: &EllipticCurve) -> Result<Scalar, Errors> {
+    let dst = format!("{}{}", FROST_CONTEXT, tag);
+    let bytes = expand_message_xmd(&data.concat(), dst.as_bytes(), 48)?;
+    let value = U512::from_big_endian(&bytes) % U512::from(curve.n);
+    Ok(Scalar::new(U256::from(value), curve))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frost_hash_to_scalar() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // hiding nonce of participant 1 in the RFC 9591 secp256k1 vectors, H3(random_bytes || secret share)
+        let data = hex::decode("7ea5ed09af19f6ff21040c07ec2d2adbd35b759da5a401d4c99dd26b82391cb2\
+                                08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c").unwrap();
+        assert_eq!(hex::encode(frost_hash_to_scalar("nonce", &[&data], &secp256k1)?.to_bytes()),
+                   "841d3a6450d7580b4da83c8e618414d0f024391f2aeb511d7579224420aa81f0");
+        assert_eq!(frost_hash_to_scalar("nonce", &[&data[..32], &data[32..]], &secp256k1)?, frost_hash_to_scalar("nonce", &[&data], &secp256k1)?);
+        Ok(())
+    }
+}
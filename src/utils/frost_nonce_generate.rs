@@ -0,0 +1,30 @@
+use crate::types::{Scalar, EllipticCurve, Errors};
+use crate::utils::frost_hash_to_scalar;
+
+/// Derives a FROST nonce from 32 fresh random bytes and the secret share (RFC 9591 nonce_generate),
+/// mixing in the secret protects against a weak random number generator.
+pub fn frost_nonce_generate(random_bytes: &[u8; 32], secret: &Scalar, curve: &EllipticCurve) -> Result<Scalar, Errors> {
+    frost_hash_to_scalar("nonce", &[random_bytes, &secret.to_bytes()], curve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::frost_test_vectors::Rfc9591Vectors;
+
+    #[test]
+    fn test_frost_nonce_generate() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let vectors = Rfc9591Vectors::load();
+        for i in vectors.participants() {
+            let secret = vectors.scalar(&format!("participant_share_{}", i), &secp256k1);
+            for kind in ["hiding", "binding"].iter() {
+                let mut random_bytes = [0u8; 32];
+                random_bytes.copy_from_slice(&vectors.bytes(&format!("{}_nonce_randomness_{}", kind, i)));
+                let nonce = frost_nonce_generate(&random_bytes, &secret, &secp256k1)?;
+                assert_eq!(hex::encode(nonce.to_bytes()), vectors.hex(&format!("{}_nonce_{}", kind, i)));
+            }
+        }
+        Ok(())
+    }
+}
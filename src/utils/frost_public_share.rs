@@ -0,0 +1,36 @@
+use crate::types::{Scalar, ECpoint, EllipticCurve, Errors};
+use crate::ec_math::{point_add, scalar_mult};
+
+/// Returns the public share f(i) * G of the participant with `identifier` i from the Feldman commitments
+/// C_j = a_j * G of the polynomial f: C_0 + i * C_1 + i^2 * C_2 + ...
+pub fn frost_public_share(identifier: &Scalar, commitments: &[ECpoint], curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+    let mut result = ECpoint::Infinity;
+    let mut power = Scalar::one(curve);
+    for commitment in commitments.iter() {
+        let term = scalar_mult(power.value(), &(*commitment).into(), curve)?;
+        result = point_add(&result.into(), &term.into(), curve)?;
+        power = power * *identifier;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::ec_math::scalar_mult_base;
+
+    #[test]
+    fn test_frost_public_share() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // f(x) = 5 + 3x + 2x^2, f(4) = 49
+        let mut commitments = Vec::new();
+        for a in [5u64, 3, 2].iter() {
+            commitments.push(scalar_mult_base(U256::from(*a), &secp256k1)?);
+        }
+        let identifier = Scalar::new(U256::from(4), &secp256k1);
+        assert_eq!(frost_public_share(&identifier, &commitments, &secp256k1)?, scalar_mult_base(U256::from(49), &secp256k1)?);
+        assert_eq!(frost_public_share(&identifier, &[], &secp256k1)?, ECpoint::Infinity);
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::types::{Scalar, ECpoint, FrostCommitment, FrostSession, EllipticCurve, Errors};
+use crate::ec_math::{point_add, scalar_mult};
+use crate::utils::{frost_hash, frost_hash_to_scalar};
+
+/// Derives the session values of a FROST signing session (RFC 9591 binding factors, group commitment and challenge),
+/// the commitments are sorted by identifier and every signer may appear once.
+pub fn frost_session(group_public_key: &ECpoint, commitments: &[FrostCommitment], message: &[u8], curve: &EllipticCurve) -> Result<FrostSession, Errors> {
+    let mut commitments = commitments.to_vec();
+    commitments.sort_by_key(|commitment| commitment.identifier.value());
+    let mut encoded = Vec::new();
+    for (i, commitment) in commitments.iter().enumerate() {
+        if i > 0 && commitments[i - 1].identifier == commitment.identifier {
+            return Err(Errors::DuplicateParticipant(commitment.identifier.value()));
+        }
+        if commitment.hiding == ECpoint::Infinity || commitment.binding == ECpoint::Infinity {
+            return Err(Errors::PointAtInfinity);
+        }
+        encoded.extend_from_slice(&commitment.identifier.to_bytes());
+        encoded.extend(commitment.hiding.to_sec1(true, curve));
+        encoded.extend(commitment.binding.to_sec1(true, curve));
+    }
+    let group_public_key_bytes = group_public_key.to_sec1(true, curve);
+    let (message_hash, commitments_hash) = (frost_hash("msg", &[message]), frost_hash("com", &[&encoded]));
+    let binding_factors = commitments.iter().map(|commitment| frost_hash_to_scalar("rho",
+        &[&group_public_key_bytes, &message_hash, &commitments_hash, &commitment.identifier.to_bytes()], curve))
+        .collect::<Result<Vec<Scalar>, Errors>>()?;
+    // R = sum of hiding_i + binding_factor_i * binding_i
+    let mut group_commitment = ECpoint::Infinity;
+    for (commitment, binding_factor) in commitments.iter().zip(binding_factors.iter()) {
+        let binding = scalar_mult(binding_factor.value(), &commitment.binding.into(), curve)?;
+        let share = point_add(&commitment.hiding.into(), &binding.into(), curve)?;
+        group_commitment = point_add(&group_commitment.into(), &share.into(), curve)?;
+    }
+    if group_commitment == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let challenge = frost_hash_to_scalar("chal", &[&group_commitment.to_sec1(true, curve), &group_public_key_bytes, message], curve)?;
+    Ok(FrostSession {commitments, binding_factors, group_commitment, challenge})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::utils::frost_test_vectors::Rfc9591Vectors;
+
+    #[test]
+    fn test_frost_session() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // participants 1 and 3 of the RFC 9591 secp256k1 vectors sign
+        let vectors = Rfc9591Vectors::load();
+        let message = vectors.bytes("message");
+        let group_public_key = vectors.point("group_public_key", &secp256k1)?;
+        let mut commitments = vectors.commitments(&secp256k1)?;
+        // the order of the commitments does not matter
+        commitments.reverse();
+        let session = frost_session(&group_public_key, &commitments, &message, &secp256k1)?;
+        assert_eq!(session.participants(), vec![Scalar::one(&secp256k1), Scalar::new(U256::from(3), &secp256k1)]);
+        for i in vectors.participants() {
+            let identifier = Scalar::new(i.parse::<u64>().unwrap().into(), &secp256k1);
+            assert_eq!(session.signer(&identifier)?.1, vectors.scalar(&format!("binding_factor_{}", i), &secp256k1));
+        }
+        assert_eq!(session.signer(&Scalar::new(U256::from(2), &secp256k1)), Err(Errors::ParticipantNotInList(U256::from(2))));
+        let twice = vec![commitments[0], commitments[0]];
+        assert_eq!(frost_session(&group_public_key, &twice, &message, &secp256k1), Err(Errors::DuplicateParticipant(commitments[0].identifier.value())));
+        let mut infinite = commitments.clone();
+        infinite[1].binding = ECpoint::Infinity;
+        assert_eq!(frost_session(&group_public_key, &infinite, &message, &secp256k1), Err(Errors::PointAtInfinity));
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::types::{Scalar, FrostKeyPackage, FrostNonces, FrostCommitment, EllipticCurve, Errors};
+use crate::group_math::lagrange_coefficient;
+use crate::utils::frost_session;
+
+/// Second round of FROST signing (RFC 9591 sign), consumes the nonces of the first round.
+/// Returns the signature share z_i = hiding_i + binding_i * rho_i + lambda_i * s_i * c, where rho_i is the binding factor,
+/// lambda_i the Lagrange coefficient of the signer among all signers and c the challenge.
+/// `commitments` are those of all signers, ParticipantNotInList is returned if ours is not among them.
+pub fn frost_sign(nonces: FrostNonces, key_package: &FrostKeyPackage, commitments: &[FrostCommitment], message: &[u8], curve: &EllipticCurve) -> Result<Scalar, Errors> {
+    let session = frost_session(&key_package.group_public_key, commitments, message, curve)?;
+    let (_, binding_factor) = session.signer(&key_package.identifier)?;
+    let participants: Vec<_> = session.participants().iter().map(|identifier| identifier.value()).collect();
+    let lambda = Scalar::new(lagrange_coefficient(key_package.identifier.value(), &participants, curve.n)?, curve);
+    Ok(nonces.hiding + nonces.binding * binding_factor + lambda * key_package.secret_share * session.challenge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::utils::frost_test_vectors::Rfc9591Vectors;
+
+    #[test]
+    fn test_frost_sign() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // participants 1 and 3 of the RFC 9591 secp256k1 vectors sign
+        let vectors = Rfc9591Vectors::load();
+        let message = vectors.bytes("message");
+        let commitments = vectors.commitments(&secp256k1)?;
+        for i in vectors.participants() {
+            let key_package = vectors.key_package(i, &secp256k1)?;
+            let nonces = || FrostNonces {
+                hiding: vectors.scalar(&format!("hiding_nonce_{}", i), &secp256k1),
+                binding: vectors.scalar(&format!("binding_nonce_{}", i), &secp256k1)
+            };
+            assert_eq!(frost_sign(nonces(), &key_package, &commitments, &message, &secp256k1)?, vectors.scalar(&format!("sig_share_{}", i), &secp256k1));
+            // participant 2 does not sign in this session
+            let outsider = FrostKeyPackage {identifier: Scalar::new(U256::from(2), &secp256k1), ..key_package};
+            assert_eq!(frost_sign(nonces(), &outsider, &commitments, &message, &secp256k1), Err(Errors::ParticipantNotInList(U256::from(2))));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use crate::types::{U256, Scalar, ECpoint, FrostKeyPackage, FrostCommitment, FrostDkgSecret, FrostDkgPackage, EllipticCurve, Errors};
+use crate::ec_math::scalar_mult_base;
+use crate::traits::GenRandU256;
+use crate::utils::{frost_dkg_part1, frost_dkg_part2, frost_dkg_part3};
+
+/// Hands out the queued values in order instead of random ones.
+pub(crate) struct MockRng {
+    values: Vec<U256>
+}
+
+impl MockRng {
+    pub(crate) fn new(values: Vec<U256>) -> Self {
+        MockRng { values }
+    }
+}
+
+impl GenRandU256 for MockRng {
+    fn gen_u256_range(&mut self, _: &U256, _: &U256) -> U256 {self.values.remove(0)}
+    fn gen_u256(&mut self) -> U256 {self.values.remove(0)}
+}
+
+/// The RFC 9591 FROST(secp256k1, SHA-256) vectors: 2-of-3 keys, participants 1 and 3 sign.
+pub(crate) struct Rfc9591Vectors {
+    values: HashMap<&'static str, &'static str>
+}
+
+impl Rfc9591Vectors {
+    pub(crate) fn load() -> Self {
+        let values = include_str!("../../test_vectors/rfc9591_secp256k1.csv").lines().skip(1)
+            .filter_map(|line| line.split_once(',')).collect();
+        Rfc9591Vectors { values }
+    }
+    /// Returns the hex value of `name`.
+    pub(crate) fn hex(&self, name: &str) -> &'static str {
+        self.values[name]
+    }
+    pub(crate) fn bytes(&self, name: &str) -> Vec<u8> {
+        hex::decode(self.hex(name)).unwrap()
+    }
+    pub(crate) fn u256(&self, name: &str) -> U256 {
+        self.hex(name).parse().unwrap()
+    }
+    pub(crate) fn scalar(&self, name: &str, curve: &EllipticCurve) -> Scalar {
+        Scalar::new(self.u256(name), curve)
+    }
+    pub(crate) fn point(&self, name: &str, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+        ECpoint::from_sec1(&self.bytes(name), curve)
+    }
+    /// Returns the identifiers of the signers as they appear in the value names ("1", "3").
+    pub(crate) fn participants(&self) -> Vec<&'static str> {
+        self.hex("participant_list").split(' ').collect()
+    }
+    pub(crate) fn key_package(&self, participant: &str, curve: &EllipticCurve) -> Result<FrostKeyPackage, Errors> {
+        let secret_share = self.scalar(&format!("participant_share_{}", participant), curve);
+        Ok(FrostKeyPackage {
+            identifier: Scalar::new(participant.parse().unwrap(), curve),
+            secret_share,
+            public_share: scalar_mult_base(secret_share.value(), curve)?,
+            group_public_key: self.point("group_public_key", curve)?
+        })
+    }
+    /// Returns the commitments of all signers.
+    pub(crate) fn commitments(&self, curve: &EllipticCurve) -> Result<Vec<FrostCommitment>, Errors> {
+        self.participants().iter().map(|participant| Ok(FrostCommitment {
+            identifier: Scalar::new(participant.parse().unwrap(), curve),
+            hiding: self.point(&format!("hiding_nonce_commitment_{}", participant), curve)?,
+            binding: self.point(&format!("binding_nonce_commitment_{}", participant), curve)?
+        })).collect()
+    }
+}
+
+/// The first two rounds of a distributed key generation of the participants 1 to max_signers,
+/// shares[i] holds what participant i + 1 sends to the others.
+pub(crate) struct DkgRounds {
+    pub(crate) secrets: Vec<FrostDkgSecret>,
+    pub(crate) packages: Vec<FrostDkgPackage>,
+    pub(crate) shares: Vec<Vec<(Scalar, Scalar)>>
+}
+
+impl DkgRounds {
+    pub(crate) fn run<R: GenRandU256>(rng: &mut R, min_signers: usize, max_signers: usize, curve: &EllipticCurve) -> Result<Self, Errors> {
+        let mut secrets = Vec::new();
+        let mut packages = Vec::new();
+        for i in 1..=max_signers {
+            let (secret, package) = frost_dkg_part1(rng, Scalar::new(U256::from(i), curve), min_signers, max_signers, curve)?;
+            secrets.push(secret);
+            packages.push(package);
+        }
+        let mut rounds = DkgRounds { secrets, packages, shares: Vec::new() };
+        for (i, secret) in rounds.secrets.iter().enumerate() {
+            rounds.shares.push(frost_dkg_part2(secret, &rounds.others(i), curve)?);
+        }
+        Ok(rounds)
+    }
+    /// Returns the round one packages of everyone but participant i + 1.
+    pub(crate) fn others(&self, i: usize) -> Vec<FrostDkgPackage> {
+        self.packages.iter().filter(|package| package.identifier != self.packages[i].identifier).cloned().collect()
+    }
+    /// Returns the shares participant i + 1 received as (sender, share).
+    pub(crate) fn received(&self, i: usize) -> Vec<(Scalar, Scalar)> {
+        self.shares.iter().enumerate().filter(|(j, _)| *j != i)
+            .map(|(j, sent)| (self.packages[j].identifier, sent.iter().find(|(recipient, _)| *recipient == self.packages[i].identifier).unwrap().1))
+            .collect()
+    }
+    /// Runs the last round for every participant.
+    pub(crate) fn key_packages(self, curve: &EllipticCurve) -> Result<Vec<FrostKeyPackage>, Errors> {
+        let (others, received): (Vec<_>, Vec<_>) = (0..self.secrets.len()).map(|i| (self.others(i), self.received(i))).unzip();
+        self.secrets.into_iter().zip(others.iter().zip(received.iter()))
+            .map(|(secret, (others, received))| frost_dkg_part3(secret, others, received, curve)).collect()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::types::{U256, Scalar, ECpoint, FrostKeyPackage, EllipticCurve, Errors};
+use crate::ec_math::scalar_mult_base;
+use crate::group_math::polynomial_evaluate;
+use crate::traits::GenRandU256;
+
+/// Splits `secret` into `max_signers` shares of which any `min_signers` can sign (RFC 9591 trusted_dealer_keygen).
+/// The secret is the constant term of a random polynomial of degree min_signers - 1, participant i gets f(i).
+/// Returns the key packages of participants 1..=max_signers and the Feldman commitments to the polynomial,
+/// which the dealer publishes so that every participant can check its share with `frost_vss_verify`.
+pub fn frost_trusted_dealer_keygen<R: GenRandU256>(rng: &mut R, secret: Scalar, min_signers: usize, max_signers: usize, curve: &EllipticCurve) -> Result<(Vec<FrostKeyPackage>, Vec<ECpoint>), Errors> {
+    if min_signers < 2 || min_signers > max_signers {
+        return Err(Errors::InvalidThreshold(min_signers, max_signers));
+    }
+    if secret.is_zero() {
+        return Err(Errors::ZeroPrivateKey);
+    }
+    let mut coefficients = vec![secret.value()];
+    for _ in 1..min_signers {
+        coefficients.push(rng.gen_u256_range(&U256::one(), &curve.n));
+    }
+    let mut commitments = Vec::with_capacity(min_signers);
+    for coefficient in coefficients.iter() {
+        commitments.push(scalar_mult_base(*coefficient, curve)?);
+    }
+    let mut key_packages = Vec::with_capacity(max_signers);
+    for i in 1..=max_signers {
+        let secret_share = Scalar::new(polynomial_evaluate(&coefficients, i as u64, curve.n)?, curve);
+        key_packages.push(FrostKeyPackage {
+            identifier: Scalar::new(U256::from(i), curve),
+            secret_share,
+            public_share: scalar_mult_base(secret_share.value(), curve)?,
+            group_public_key: commitments[0]
+        });
+    }
+    Ok((key_packages, commitments))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::frost_vss_verify;
+    use crate::utils::frost_test_vectors::{MockRng, Rfc9591Vectors};
+
+    #[test]
+    fn test_frost_trusted_dealer_keygen() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // 2-of-3 key generation of the RFC 9591 secp256k1 vectors
+        let vectors = Rfc9591Vectors::load();
+        let secret = vectors.scalar("group_secret_key", &secp256k1);
+        let mut rng = MockRng::new(vec![vectors.u256("share_polynomial_coefficient_1")]);
+        let (key_packages, commitments) = frost_trusted_dealer_keygen(&mut rng, secret, 2, 3, &secp256k1)?;
+        assert_eq!(key_packages.len(), 3);
+        for key_package in key_packages.iter() {
+            let i = key_package.identifier.value().low_u64();
+            assert_eq!(hex::encode(key_package.secret_share.to_bytes()), vectors.hex(&format!("participant_share_{}", i)));
+            assert_eq!(hex::encode(key_package.group_public_key.to_sec1(true, &secp256k1)), vectors.hex("group_public_key"));
+            assert!(frost_vss_verify(&key_package.identifier, &key_package.secret_share, &commitments, &secp256k1)?);
+        }
+        let mut rng = rand::thread_rng();
+        assert_eq!(frost_trusted_dealer_keygen(&mut rng, secret, 1, 3, &secp256k1), Err(Errors::InvalidThreshold(1, 3)));
+        assert_eq!(frost_trusted_dealer_keygen(&mut rng, secret, 4, 3, &secp256k1), Err(Errors::InvalidThreshold(4, 3)));
+        assert_eq!(frost_trusted_dealer_keygen(&mut rng, Scalar::zero(&secp256k1), 2, 3, &secp256k1), Err(Errors::ZeroPrivateKey));
+        Ok(())
+    }
+}
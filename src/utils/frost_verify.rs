@@ -0,0 +1,82 @@
+use crate::types::{ECpoint, FrostSignature, SignatureVerification, EllipticCurve, Errors};
+use crate::ec_math::{point_add, scalar_mult, scalar_mult_base};
+use crate::utils::frost_hash_to_scalar;
+
+/// Verifies a FROST group signature (R, z) like a plain Schnorr signature of the group key:
+/// z * G = R + c * group_public_key with c = H2(R || group_public_key || message).
+pub fn frost_verify(group_public_key: &ECpoint, message: &[u8], signature: &FrostSignature, curve: &EllipticCurve) -> Result<SignatureVerification, Errors> {
+    if signature.r == ECpoint::Infinity || *group_public_key == ECpoint::Infinity {
+        return Ok(SignatureVerification::Failed);
+    }
+    let c = frost_hash_to_scalar("chal", &[&signature.r.to_sec1(true, curve), &group_public_key.to_sec1(true, curve), message], curve)?;
+    let c_key = scalar_mult(c.value(), &(*group_public_key).into(), curve)?;
+    match scalar_mult_base(signature.z.value(), curve)? == point_add(&signature.r.into(), &c_key.into(), curve)? {
+        true => Ok(SignatureVerification::Successful),
+        false => Ok(SignatureVerification::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, Scalar, FrostKeyPackage};
+    use crate::traits::GenRandU256;
+    use crate::utils::{frost_trusted_dealer_keygen, frost_commit, frost_sign, frost_verify_share, frost_aggregate};
+    use crate::utils::frost_test_vectors::{Rfc9591Vectors, DkgRounds};
+
+    // signs `message` with the given signers, every step in process
+    fn sign(signers: &[&FrostKeyPackage], message: &[u8], curve: &EllipticCurve) -> Result<FrostSignature, Errors> {
+        let mut rng = rand::thread_rng();
+        let mut nonces = Vec::new();
+        let mut commitments = Vec::new();
+        for signer in signers.iter() {
+            let (nonce, commitment) = frost_commit(&mut rng, signer, curve)?;
+            nonces.push(nonce);
+            commitments.push(commitment);
+        }
+        let mut shares = Vec::new();
+        for (signer, nonce) in signers.iter().zip(nonces) {
+            let share = frost_sign(nonce, signer, &commitments, message, curve)?;
+            assert_eq!(frost_verify_share(&signer.identifier, &signer.public_share, &share, &commitments, &signer.group_public_key, message, curve)?,
+                       SignatureVerification::Successful);
+            shares.push(share);
+        }
+        frost_aggregate(&shares, &commitments, &signers[0].group_public_key, message, curve)
+    }
+
+    #[test]
+    fn test_frost_verify() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let vectors = Rfc9591Vectors::load();
+        let group_public_key = vectors.point("group_public_key", &secp256k1)?;
+        let message = vectors.bytes("message");
+        let mut bytes = [0u8; 65];
+        bytes.copy_from_slice(&vectors.bytes("sig"));
+        let signature = FrostSignature::from_bytes(&bytes, &secp256k1)?;
+        assert_eq!(frost_verify(&group_public_key, &message, &signature, &secp256k1)?, SignatureVerification::Successful);
+        assert_eq!(frost_verify(&group_public_key, b"other", &signature, &secp256k1)?, SignatureVerification::Failed);
+
+        // 2-of-3 with a trusted dealer, every pair of participants can sign
+        let mut rng = rand::thread_rng();
+        let secret = Scalar::new(rng.gen_u256_range(&U256::one(), &secp256k1.n), &secp256k1);
+        let (key_packages, _) = frost_trusted_dealer_keygen(&mut rng, secret, 2, 3, &secp256k1)?;
+        assert_eq!(key_packages[0].group_public_key, scalar_mult_base(secret.value(), &secp256k1)?);
+        for (a, b) in [(0, 1), (0, 2), (1, 2)].iter() {
+            let signature = sign(&[&key_packages[*a], &key_packages[*b]], b"dealer", &secp256k1)?;
+            assert_eq!(frost_verify(&key_packages[0].group_public_key, b"dealer", &signature, &secp256k1)?, SignatureVerification::Successful);
+        }
+        let signature = sign(&[&key_packages[2], &key_packages[0], &key_packages[1]], b"dealer", &secp256k1)?;
+        assert_eq!(frost_verify(&key_packages[0].group_public_key, b"dealer", &signature, &secp256k1)?, SignatureVerification::Successful);
+        // a single share is not enough
+        let signature = sign(&[&key_packages[1]], b"dealer", &secp256k1)?;
+        assert_eq!(frost_verify(&key_packages[0].group_public_key, b"dealer", &signature, &secp256k1)?, SignatureVerification::Failed);
+
+        // 2-of-3 without a dealer
+        let key_packages = DkgRounds::run(&mut rng, 2, 3, &secp256k1)?.key_packages(&secp256k1)?;
+        for (a, b) in [(0, 1), (0, 2), (1, 2)].iter() {
+            let signature = sign(&[&key_packages[*a], &key_packages[*b]], b"dkg", &secp256k1)?;
+            assert_eq!(frost_verify(&key_packages[*a].group_public_key, b"dkg", &signature, &secp256k1)?, SignatureVerification::Successful);
+        }
+        Ok(())
+    }
+}
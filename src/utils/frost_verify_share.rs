@@ -0,0 +1,46 @@
+use crate::types::{Scalar, ECpoint, FrostCommitment, SignatureVerification, EllipticCurve, Errors};
+use crate::ec_math::{point_add, scalar_mult, scalar_mult_base};
+use crate::group_math::lagrange_coefficient;
+use crate::utils::frost_session;
+
+/// Checks the signature share of one signer (RFC 9591 verify_signature_share) so the aggregator can tell who misbehaved:
+/// z_i * G = hiding_i + rho_i * binding_i + c * lambda_i * public_share. `commitments` are those of all signers.
+pub fn frost_verify_share(identifier: &Scalar, public_share: &ECpoint, share: &Scalar, commitments: &[FrostCommitment],
+                          group_public_key: &ECpoint, message: &[u8], curve: &EllipticCurve) -> Result<SignatureVerification, Errors> {
+    let session = frost_session(group_public_key, commitments, message, curve)?;
+    let (commitment, binding_factor) = session.signer(identifier)?;
+    let participants: Vec<_> = session.participants().iter().map(|identifier| identifier.value()).collect();
+    let lambda = Scalar::new(lagrange_coefficient(identifier.value(), &participants, curve.n)?, curve);
+    let binding = scalar_mult(binding_factor.value(), &commitment.binding.into(), curve)?;
+    let commitment_share = point_add(&commitment.hiding.into(), &binding.into(), curve)?;
+    let key = scalar_mult((session.challenge * lambda).value(), &(*public_share).into(), curve)?;
+    match scalar_mult_base(share.value(), curve)? == point_add(&commitment_share.into(), &key.into(), curve)? {
+        true => Ok(SignatureVerification::Successful),
+        false => Ok(SignatureVerification::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::frost_test_vectors::Rfc9591Vectors;
+
+    #[test]
+    fn test_frost_verify_share() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let vectors = Rfc9591Vectors::load();
+        let group_public_key = vectors.point("group_public_key", &secp256k1)?;
+        let message = vectors.bytes("message");
+        let commitments = vectors.commitments(&secp256k1)?;
+        for i in vectors.participants() {
+            let key_package = vectors.key_package(i, &secp256k1)?;
+            let (identifier, public_share) = (key_package.identifier, key_package.public_share);
+            let share = vectors.scalar(&format!("sig_share_{}", i), &secp256k1);
+            assert_eq!(frost_verify_share(&identifier, &public_share, &share, &commitments, &group_public_key, &message, &secp256k1)?, SignatureVerification::Successful);
+            let share = share + Scalar::one(&secp256k1);
+            assert_eq!(frost_verify_share(&identifier, &public_share, &share, &commitments, &group_public_key, &message, &secp256k1)?, SignatureVerification::Failed);
+            assert_eq!(frost_verify_share(&identifier, &public_share, &share, &commitments, &group_public_key, b"other", &secp256k1)?, SignatureVerification::Failed);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::types::{Scalar, ECpoint, EllipticCurve, Errors};
+use crate::ec_math::scalar_mult_base;
+use crate::utils::frost_public_share;
+
+/// Checks a secret share against the Feldman commitments of the polynomial it was taken from (RFC 9591 vss_verify),
+/// so a participant can tell that the dealer gave it a share consistent with everyone else's.
+pub fn frost_vss_verify(identifier: &Scalar, secret_share: &Scalar, commitments: &[ECpoint], curve: &EllipticCurve) -> Result<bool, Errors> {
+    Ok(scalar_mult_base(secret_share.value(), curve)? == frost_public_share(identifier, commitments, curve)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+
+    #[test]
+    fn test_frost_vss_verify() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // f(x) = 5 + 3x, f(2) = 11
+        let commitments = [scalar_mult_base(U256::from(5), &secp256k1)?, scalar_mult_base(U256::from(3), &secp256k1)?];
+        let identifier = Scalar::new(U256::from(2), &secp256k1);
+        assert!(frost_vss_verify(&identifier, &Scalar::new(U256::from(11), &secp256k1), &commitments, &secp256k1)?);
+        assert!(!frost_vss_verify(&identifier, &Scalar::new(U256::from(12), &secp256k1), &commitments, &secp256k1)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use ring::digest::{digest, SHA256};
+use ripemd::{Ripemd160, Digest};
+
+/// Returns RIPEMD-160(SHA-256(data)), the hash Bitcoin uses for public key addresses.
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    let sha256 = digest(&SHA256, data);
+    let mut hash = [0u8; 20];
+    hash.copy_from_slice(&Ripemd160::digest(sha256.as_ref()));
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash160() {
+        assert_eq!(hex::encode(hash160(b"")), "b472a266d0bd89c13706a4132ccfb16f7c3b9fcb");
+        // compressed public key of private key 1
+        let g = hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap();
+        assert_eq!(hex::encode(hash160(&g)), "751e76e8199196d454941c45d1b3a323f1433bd6");
+    }
+}
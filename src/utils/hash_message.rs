@@ -1,15 +1,16 @@
-use crate::types::{U256, U512};
-use ring::digest::{digest, SHA512};
+use crate::types::{U256, U512, Errors, MessageDigest};
 
-/// Returns the truncated SHA512 hash of the message to `bits` length.
-pub fn hash_message(message: &[u8], bits: usize) -> U256 {
-    let digest = digest(&SHA512, message);
-    let digest: U512 = digest.as_ref().into();
-    // FIPS 180 says that when a hash needs to be truncated, the rightmost bits
-    // should be discarded.
-    // https://security.stackexchange.com/questions/72673/how-bad-is-it-to-truncate-a-hash#72675
-    let digest = digest >> (digest.bits() - bits);
-    U256::from(digest)
+/// Returns the hash of the message computed with `digest`, truncated to its leftmost `bits` bits (SEC1 4.1.3).
+pub fn hash_message(message: &[u8], digest: MessageDigest, bits: usize) -> Result<U256, Errors> {
+    let hash = digest.hash(message)?;
+    let hash_bits = 8 * hash.len();
+    let value = U512::from_big_endian(&hash);
+    // SEC1 keeps the leftmost bits of the hash (counting leading zeros), the rightmost ones are discarded.
+    let value = match hash_bits > bits {
+        true => value >> (hash_bits - bits),
+        false => value
+    };
+    Ok(U256::from(value))
 }
 
 #[cfg(test)]
@@ -17,10 +18,17 @@ mod tests {
     use super::*;
     use crate::types::EllipticCurve;
     #[test]
-    fn test_hash_message() {
+    fn test_hash_message() -> Result<(), Errors> {
         let secp256k1 = EllipticCurve::secp256k1_factory();
-        assert_eq!(format!("{:x}",hash_message(b"This is a test string", secp256k1.n.bits())), "b8ee69b29956b0b56e26d0a25c6a80713c858cf2902a12962aad08d682345646");
-        assert_eq!(format!("{:x}",hash_message(b"This is a test string\n", secp256k1.n.bits())), "ab6ddc5c40d0ed2fcdbf00c71ff80811c2b6eb274dccce690a50a6f7595fca2a");
-        assert_eq!(format!("{:x}",hash_message(b".", secp256k1.n.bits())), "b61241d7c17bcbb1baee7094d14b7c451efecc7ffcbd92598a0f13d313cc9ebc");
+        let bits = secp256k1.n.bits();
+        assert_eq!(format!("{:x}",hash_message(b"This is a test string", MessageDigest::Sha512, bits)?), "b8ee69b29956b0b56e26d0a25c6a80713c858cf2902a12962aad08d682345646");
+        assert_eq!(format!("{:x}",hash_message(b"This is a test string\n", MessageDigest::Sha512, bits)?), "ab6ddc5c40d0ed2fcdbf00c71ff80811c2b6eb274dccce690a50a6f7595fca2a");
+        // the leading zero bits of the hash count towards the kept bits
+        assert_eq!(hash_message(b".", MessageDigest::Sha512, bits)?, U256::from("0b61241d7c17bcbb1baee7094d14b7c451efecc7ffcbd92598a0f13d313cc9eb"));
+        // hashes not longer than n are used as they are
+        assert_eq!(format!("{:x}",hash_message(b"abc", MessageDigest::Sha256, bits)?), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(format!("{:x}",hash_message(b"abc", MessageDigest::Sha256, 192)?), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9c");
+        assert_eq!(hash_message(b"abc", MessageDigest::Prehashed, bits), Err(Errors::InvalidPrehashLength(3)));
+        Ok(())
     }
 }
@@ -1,12 +1,12 @@
-use crate::types::{U256, EllipticCurve, Errors, Points, Point, ECpoint};
-use crate::ec_math::scalar_mult;
+use crate::types::{U256, Scalar, EllipticCurve, Errors, ECpoint};
+use crate::ec_math::scalar_mult_base;
 use crate::traits::GenRandU256;
 
 /// Generates a random prive-public key pair.
-pub fn make_keypair(curve: &EllipticCurve) -> Result<(U256, ECpoint), Errors> {
+pub fn make_keypair(curve: &EllipticCurve) -> Result<(Scalar, ECpoint), Errors> {
     let mut rng = rand::thread_rng();
-    let private_key = rng.gen_u256_range(&U256::one(), &curve.n);
-    let public_key = scalar_mult(private_key, &Points::FinitePoint(Point::from(curve.g)), curve)?;
+    let private_key = Scalar::new(rng.gen_u256_range(&U256::one(), &curve.n), curve);
+    let public_key = scalar_mult_base(private_key.value(), curve)?;
     Ok((private_key, public_key))
 
 }
@@ -14,12 +14,14 @@ pub fn make_keypair(curve: &EllipticCurve) -> Result<(U256, ECpoint), Errors> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{Points, Point};
+    use crate::ec_math::scalar_mult;
 
     #[test]
     fn test_make_keypair() -> Result<(), Errors>{
         let secp256k1 = EllipticCurve::secp256k1_factory();
         let (private_key, public_key) = make_keypair(&secp256k1)?;
-        assert_eq!(public_key, scalar_mult(private_key, &Points::FinitePoint(Point::from(secp256k1.g)), &secp256k1)?);
+        assert_eq!(public_key, scalar_mult(private_key.value(), &Points::FinitePoint(Point::from(secp256k1.g)), &secp256k1)?);
         Ok(())
     }
 }
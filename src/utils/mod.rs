@@ -1,8 +1,111 @@
+pub use crate::hashing::{tagged_hash, compact_size};
 pub mod make_keypair;
 pub use self::make_keypair::make_keypair;
 pub mod hash_message;
 pub use self::hash_message::hash_message;
+pub mod sign_message_with_rng;
+pub use self::sign_message_with_rng::sign_message_with_rng;
+pub mod sign_message_with_entropy;
+pub use self::sign_message_with_entropy::sign_message_with_entropy;
 pub mod sign_message;
 pub use self::sign_message::sign_message;
+pub mod dleq_prove;
+pub use self::dleq_prove::dleq_prove;
+pub mod dleq_verify;
+pub use self::dleq_verify::dleq_verify;
+pub mod schnorr_adaptor_sign;
+pub use self::schnorr_adaptor_sign::schnorr_adaptor_sign;
+pub mod schnorr_adaptor_verify;
+pub use self::schnorr_adaptor_verify::schnorr_adaptor_verify;
+pub mod schnorr_adaptor_complete;
+pub use self::schnorr_adaptor_complete::schnorr_adaptor_complete;
+pub mod schnorr_adaptor_extract;
+pub use self::schnorr_adaptor_extract::schnorr_adaptor_extract;
+pub mod ecdsa_adaptor_sign;
+pub use self::ecdsa_adaptor_sign::ecdsa_adaptor_sign;
+pub mod ecdsa_adaptor_verify;
+pub use self::ecdsa_adaptor_verify::ecdsa_adaptor_verify;
+pub mod ecdsa_adaptor_complete;
+pub use self::ecdsa_adaptor_complete::ecdsa_adaptor_complete;
+pub mod ecdsa_adaptor_extract;
+pub use self::ecdsa_adaptor_extract::ecdsa_adaptor_extract;
+pub mod sign_message_recoverable;
+pub use self::sign_message_recoverable::sign_message_recoverable;
+pub mod recover_public_key;
+pub use self::recover_public_key::recover_public_key;
+pub mod verify_signature_with_policy;
+pub use self::verify_signature_with_policy::verify_signature_with_policy;
 pub mod verify_signature;
 pub use self::verify_signature::verify_signature;
+pub mod verify_batch;
+pub use self::verify_batch::verify_batch;
+pub mod hash160;
+pub use self::hash160::hash160;
+pub mod base58check_encode;
+pub use self::base58check_encode::base58check_encode;
+pub mod p2pkh_address;
+pub use self::p2pkh_address::p2pkh_address;
+pub mod bitcoin_message;
+pub use self::bitcoin_message::bitcoin_message;
+pub mod sign_bitcoin_message;
+pub use self::sign_bitcoin_message::sign_bitcoin_message;
+pub mod verify_bitcoin_message;
+pub use self::verify_bitcoin_message::verify_bitcoin_message;
+pub mod schnorr_sign;
+pub use self::schnorr_sign::schnorr_sign;
+pub mod schnorr_verify;
+pub use self::schnorr_verify::schnorr_verify;
+pub mod taproot_tweak_private_key;
+pub use self::taproot_tweak_private_key::taproot_tweak_private_key;
+pub mod musig_key_sort;
+pub use self::musig_key_sort::musig_key_sort;
+pub mod musig_key_agg;
+pub use self::musig_key_agg::musig_key_agg;
+pub mod musig_nonce_gen;
+pub use self::musig_nonce_gen::musig_nonce_gen;
+pub mod musig_nonce_agg;
+pub use self::musig_nonce_agg::musig_nonce_agg;
+pub mod musig_apply_tweak;
+pub use self::musig_apply_tweak::musig_apply_tweak;
+pub mod musig_session;
+pub use self::musig_session::musig_session;
+pub mod musig_partial_verify;
+pub use self::musig_partial_verify::musig_partial_verify;
+pub mod musig_partial_sign;
+pub use self::musig_partial_sign::musig_partial_sign;
+pub mod musig_partial_sig_agg;
+pub use self::musig_partial_sig_agg::musig_partial_sig_agg;
+pub mod expand_message_xmd;
+pub use self::expand_message_xmd::expand_message_xmd;
+pub mod frost_hash_to_scalar;
+pub use self::frost_hash_to_scalar::frost_hash_to_scalar;
+pub mod frost_hash;
+pub use self::frost_hash::frost_hash;
+pub mod frost_nonce_generate;
+pub use self::frost_nonce_generate::frost_nonce_generate;
+pub mod frost_public_share;
+pub use self::frost_public_share::frost_public_share;
+pub mod frost_vss_verify;
+pub use self::frost_vss_verify::frost_vss_verify;
+pub mod frost_trusted_dealer_keygen;
+pub use self::frost_trusted_dealer_keygen::frost_trusted_dealer_keygen;
+pub mod frost_dkg_part1;
+pub use self::frost_dkg_part1::frost_dkg_part1;
+pub mod frost_dkg_part2;
+pub use self::frost_dkg_part2::frost_dkg_part2;
+pub mod frost_dkg_part3;
+pub use self::frost_dkg_part3::frost_dkg_part3;
+pub mod frost_session;
+pub use self::frost_session::frost_session;
+pub mod frost_commit;
+pub use self::frost_commit::frost_commit;
+pub mod frost_sign;
+pub use self::frost_sign::frost_sign;
+pub mod frost_verify_share;
+pub use self::frost_verify_share::frost_verify_share;
+pub mod frost_aggregate;
+pub use self::frost_aggregate::frost_aggregate;
+pub mod frost_verify;
+pub use self::frost_verify::frost_verify;
+#[cfg(test)]
+pub(crate) mod frost_test_vectors;
@@ -0,0 +1,86 @@
+use crate::types::{Scalar, Point, Points, ECpoint, KeyAggContext, EllipticCurve, Errors};
+use crate::ec_math::multi_scalar_mult;
+
+/// Returns the context with Q replaced by g * Q + t * G (BIP327 ApplyTweak), the tweak t must be below n.
+/// For an x only tweak (e.g. a BIP341 TapTweak) g is the sign that gives Q an even y, otherwise g is 1.
+pub fn musig_apply_tweak(key_agg: &KeyAggContext, tweak: &[u8; 32], x_only: bool, curve: &EllipticCurve) -> Result<KeyAggContext, Errors> {
+    let t = Scalar::from_bytes(tweak, curve)?;
+    if key_agg.aggregate_key == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let g = match x_only {
+        true => key_agg.key_sign(curve),
+        false => Scalar::one(curve)
+    };
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    #[allow(non_snake_case)]
+    let Q = multi_scalar_mult(&[(g.value(), key_agg.aggregate_key.into()), (t.value(), G)], curve)?;
+    if Q == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    Ok(KeyAggContext {
+        public_keys: key_agg.public_keys.clone(),
+        coefficients: key_agg.coefficients.clone(),
+        aggregate_key: Q,
+        gacc: g * key_agg.gacc,
+        tacc: t + g * key_agg.tacc
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, MusigSecretNonce, MusigPublicNonce, MusigAggregateNonce, SignatureVerification};
+    use crate::utils::{musig_key_agg, musig_nonce_agg, musig_partial_sign, musig_partial_verify};
+
+    #[test]
+    fn test_musig_apply_tweak() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // BIP327 tweak vectors: partial signatures of a key tweaked by plain and x only tweaks
+        let vectors = include_str!("../../test_vectors/bip327_tweak.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let private_key = Scalar::new(fields[0].parse().unwrap(), &secp256k1);
+            let mut bytes = [0u8; 97];
+            bytes.copy_from_slice(&hex::decode(fields[1]).unwrap());
+            let secret_nonce = MusigSecretNonce::from_bytes(&bytes, &secp256k1)?;
+            let mut keys = Vec::new();
+            for key in fields[2].split(' ') {
+                keys.push(ECpoint::from_sec1(&hex::decode(key).unwrap(), &secp256k1)?);
+            }
+            let mut nonces = Vec::new();
+            for nonce in fields[3].split(' ') {
+                let mut bytes = [0u8; 66];
+                bytes.copy_from_slice(&hex::decode(nonce).unwrap());
+                nonces.push(MusigPublicNonce::from_bytes(&bytes, &secp256k1)?);
+            }
+            let mut bytes = [0u8; 66];
+            bytes.copy_from_slice(&hex::decode(fields[4]).unwrap());
+            let aggregate_nonce = MusigAggregateNonce::from_bytes(&bytes, &secp256k1)?;
+            assert_eq!(musig_nonce_agg(&nonces, &secp256k1)?, aggregate_nonce);
+            let message = hex::decode(fields[5]).unwrap();
+            let mut key_agg = Ok(musig_key_agg(&keys, &secp256k1)?);
+            for (tweak, x_only) in fields[6].split(' ').zip(fields[7].split(' ')) {
+                let mut bytes = [0u8; 32];
+                bytes.copy_from_slice(&hex::decode(tweak).unwrap());
+                key_agg = key_agg.and_then(|key_agg| musig_apply_tweak(&key_agg, &bytes, x_only == "1", &secp256k1));
+            }
+            if fields[10] == "tweak" {
+                assert_eq!(key_agg, Err(Errors::ScalarOutOfRange("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141".parse::<U256>().unwrap())));
+                count += 1;
+                continue;
+            }
+            let key_agg = key_agg?;
+            let s = musig_partial_sign(secret_nonce, private_key, &key_agg, &aggregate_nonce, &message, &secp256k1)?;
+            assert_eq!(hex::encode(s.to_bytes()), fields[9], "line {}", line);
+            let signer: usize = fields[8].parse().unwrap();
+            assert_eq!(musig_partial_verify(&s, &nonces[signer], &keys[signer], &key_agg, &aggregate_nonce, &message, &secp256k1)?,
+                       SignatureVerification::Successful);
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        Ok(())
+    }
+}
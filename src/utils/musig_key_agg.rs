@@ -0,0 +1,73 @@
+use crate::types::{U256, Scalar, ECpoint, KeyAggContext, EllipticCurve, Errors};
+use crate::ec_math::multi_scalar_mult;
+use crate::utils::tagged_hash;
+
+/// Aggregates the signers' public keys into one MuSig2 key (BIP327 KeyAgg): Q = a1 * P1 + ... + au * Pu.
+/// The coefficients hash the whole key list, the first key different from P1 gets the coefficient 1.
+/// The order of the keys matters, see `musig_key_sort`. Returns PointAtInfinity if a key or Q is infinity.
+pub fn musig_key_agg(public_keys: &[ECpoint], curve: &EllipticCurve) -> Result<KeyAggContext, Errors> {
+    if public_keys.is_empty() || public_keys.contains(&ECpoint::Infinity) {
+        return Err(Errors::PointAtInfinity);
+    }
+    let encoded: Vec<Vec<u8>> = public_keys.iter().map(|key| key.to_sec1(true, curve)).collect();
+    let parts: Vec<&[u8]> = encoded.iter().map(|key| key.as_slice()).collect();
+    let list_hash = tagged_hash("KeyAgg list", &parts);
+    let second_key = public_keys.iter().find(|key| **key != public_keys[0]);
+    let coefficients: Vec<Scalar> = public_keys.iter().zip(parts.iter()).map(|(key, bytes)| match Some(key) == second_key {
+        true => Scalar::one(curve),
+        false => Scalar::from_bytes_reduced(&tagged_hash("KeyAgg coefficient", &[&list_hash, bytes]), curve)
+    }).collect();
+    let terms: Vec<_> = coefficients.iter().zip(public_keys.iter()).map(|(a, key)| (a.value(), (*key).into())).collect();
+    #[allow(non_snake_case)]
+    let Q = multi_scalar_mult(&terms, curve)?;
+    if Q == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    Ok(KeyAggContext {
+        public_keys: public_keys.to_vec(),
+        coefficients,
+        aggregate_key: Q,
+        gacc: Scalar::one(curve),
+        tacc: Scalar::new(U256::zero(), curve)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::musig_apply_tweak;
+
+    #[test]
+    fn test_musig_key_agg() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // BIP327 key aggregation vectors, invalid keys fail to parse and invalid tweaks fail to apply
+        let vectors = include_str!("../../test_vectors/bip327_key_agg.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let keys: Result<Vec<ECpoint>, Errors> = fields[0].split(' ')
+                .map(|key| ECpoint::from_sec1(&hex::decode(key).unwrap(), &secp256k1)).collect();
+            if fields[4] == "pubkey" {
+                assert!(keys.is_err(), "line {}", line);
+                count += 1;
+                continue;
+            }
+            let mut key_agg = musig_key_agg(&keys?, &secp256k1)?;
+            if fields[4] == "tweak" {
+                let mut tweak = [0u8; 32];
+                tweak.copy_from_slice(&hex::decode(fields[1]).unwrap());
+                assert!(musig_apply_tweak(&key_agg, &tweak, fields[2] == "1", &secp256k1).is_err(), "line {}", line);
+                count += 1;
+                continue;
+            }
+            assert_eq!(hex::encode(key_agg.x_only_public_key(&secp256k1)?.to_bytes()), fields[3]);
+            // a zero tweak keeps the key
+            key_agg = musig_apply_tweak(&key_agg, &[0u8; 32], false, &secp256k1)?;
+            assert_eq!(hex::encode(key_agg.x_only_public_key(&secp256k1)?.to_bytes()), fields[3]);
+            count += 1;
+        }
+        assert_eq!(count, 9);
+        assert_eq!(musig_key_agg(&[], &secp256k1), Err(Errors::PointAtInfinity));
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::types::{ECpoint, EllipticCurve};
+
+/// Sorts public keys by their compressed encoding (BIP327 KeySort), so signers agree on one order.
+pub fn musig_key_sort(public_keys: &[ECpoint], curve: &EllipticCurve) -> Vec<ECpoint> {
+    let mut keys = public_keys.to_vec();
+    keys.sort_by_cached_key(|key| key.to_sec1(true, curve));
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Errors;
+
+    #[test]
+    fn test_musig_key_sort() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // keys of the BIP327 key aggregation vectors, sorted they give the second valid case
+        let keys = ["02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "03dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            "023590a94e768f8e1815c2f24b4d80a8e3149316c3518ce7b7ad338368d038ca66"];
+        let mut points = Vec::new();
+        for key in keys.iter() {
+            points.push(ECpoint::from_sec1(&hex::decode(key).unwrap(), &secp256k1)?);
+        }
+        let sorted = musig_key_sort(&points, &secp256k1);
+        assert_eq!(sorted, vec![points[2], points[0], points[1]]);
+        assert_eq!(musig_key_sort(&sorted, &secp256k1), sorted);
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::types::{U256, Points, MusigPublicNonce, MusigAggregateNonce, EllipticCurve, Errors};
+use crate::ec_math::multi_scalar_mult;
+
+/// Sums the signers' public nonces into the aggregate nonce (BIP327 NonceAgg), the end of the first round.
+pub fn musig_nonce_agg(public_nonces: &[MusigPublicNonce], curve: &EllipticCurve) -> Result<MusigAggregateNonce, Errors> {
+    let r1: Vec<(U256, Points)> = public_nonces.iter().map(|nonce| (U256::one(), nonce.r1.into())).collect();
+    let r2: Vec<(U256, Points)> = public_nonces.iter().map(|nonce| (U256::one(), nonce.r2.into())).collect();
+    Ok(MusigAggregateNonce::new(multi_scalar_mult(&r1, curve)?, multi_scalar_mult(&r2, curve)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_musig_nonce_agg() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // BIP327 nonce aggregation vectors, the second one sums to infinity in R2
+        let vectors = include_str!("../../test_vectors/bip327_nonce_agg.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let nonces: Vec<Result<MusigPublicNonce, Errors>> = fields[0].split(' ').map(|nonce| {
+                let mut bytes = [0u8; 66];
+                bytes.copy_from_slice(&hex::decode(nonce).unwrap());
+                MusigPublicNonce::from_bytes(&bytes, &secp256k1)
+            }).collect();
+            match fields[2].parse::<usize>() {
+                Ok(invalid) => assert!(nonces[invalid].is_err(), "line {}", line),
+                Err(_) => {
+                    let nonces: Result<Vec<MusigPublicNonce>, Errors> = nonces.into_iter().collect();
+                    let aggregate_nonce = musig_nonce_agg(&nonces?, &secp256k1)?;
+                    assert_eq!(hex::encode(aggregate_nonce.to_bytes(&secp256k1)), fields[1]);
+                    let mut bytes = [0u8; 66];
+                    bytes.copy_from_slice(&hex::decode(fields[1]).unwrap());
+                    assert_eq!(MusigAggregateNonce::from_bytes(&bytes, &secp256k1)?, aggregate_nonce);
+                }
+            }
+            count += 1;
+        }
+        assert_eq!(count, 5);
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::types::{Scalar, ECpoint, XOnlyPublicKey, MusigSecretNonce, MusigPublicNonce, EllipticCurve, Errors};
+use crate::ec_math::scalar_mult_base;
+use crate::utils::tagged_hash;
+
+/// Generates a MuSig2 nonce pair (BIP327 NonceGen) for the signer with `public_key`, the first round of signing.
+/// `rand` must be fresh randomness for every call. The optional private key, aggregate key, message and
+/// extra input are mixed in as a safeguard against bad randomness.
+pub fn musig_nonce_gen(rand: &[u8; 32], private_key: Option<Scalar>, public_key: &ECpoint, aggregate_key: Option<&XOnlyPublicKey>,
+                       message: Option<&[u8]>, extra_in: Option<&[u8]>, curve: &EllipticCurve) -> Result<(MusigSecretNonce, MusigPublicNonce), Errors> {
+    if *public_key == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let mut seed = *rand;
+    if let Some(private_key) = private_key {
+        seed = private_key.to_bytes();
+        for (byte, mask) in seed.iter_mut().zip(tagged_hash("MuSig/aux", &[rand]).iter()) {
+            *byte ^= mask;
+        }
+    }
+    let public_key_bytes = public_key.to_sec1(true, curve);
+    let aggregate_key_bytes = match aggregate_key {
+        Some(key) => key.to_bytes().to_vec(),
+        None => Vec::new()
+    };
+    let message_prefixed = match message {
+        Some(message) => {
+            let mut bytes = vec![1];
+            bytes.extend_from_slice(&(message.len() as u64).to_be_bytes());
+            bytes.extend_from_slice(message);
+            bytes
+        },
+        None => vec![0]
+    };
+    let extra_in = extra_in.unwrap_or(&[]);
+    let mut k = Vec::with_capacity(2);
+    for i in 0..2u8 {
+        let hash = tagged_hash("MuSig/nonce", &[&seed, &[public_key_bytes.len() as u8], &public_key_bytes,
+            &[aggregate_key_bytes.len() as u8], &aggregate_key_bytes, &message_prefixed,
+            &(extra_in.len() as u32).to_be_bytes(), extra_in, &[i]]);
+        let k_i = Scalar::from_bytes_reduced(&hash, curve);
+        if k_i.is_zero() {
+            return Err(Errors::ZeroNonce);
+        }
+        k.push(k_i);
+    }
+    let public_nonce = MusigPublicNonce::new(scalar_mult_base(k[0].value(), curve)?, scalar_mult_base(k[1].value(), curve)?);
+    Ok((MusigSecretNonce {k1: k[0], k2: k[1], public_key: *public_key}, public_nonce))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_musig_nonce_gen() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // BIP327 nonce generation vectors, including the empty and the 38 byte message
+        let vectors = include_str!("../../test_vectors/bip327_nonce_gen.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let mut rand = [0u8; 32];
+            rand.copy_from_slice(&hex::decode(fields[0]).unwrap());
+            let private_key = match fields[1] {
+                "-" => None,
+                key => Some(Scalar::new(key.parse().unwrap(), &secp256k1))
+            };
+            let public_key = ECpoint::from_sec1(&hex::decode(fields[2]).unwrap(), &secp256k1)?;
+            let mut key = [0u8; 32];
+            let aggregate_key = match fields[3] {
+                "-" => None,
+                aggregate_key => {
+                    key.copy_from_slice(&hex::decode(aggregate_key).unwrap());
+                    Some(XOnlyPublicKey::from_bytes(&key, &secp256k1)?)
+                }
+            };
+            // "-" marks an absent value, an empty field is the empty message (or extra input)
+            let (message, extra_in) = (hex::decode(fields[4]).unwrap_or_default(), hex::decode(fields[5]).unwrap_or_default());
+            let message = match fields[4] {
+                "-" => None,
+                _ => Some(message.as_slice())
+            };
+            let extra_in = match fields[5] {
+                "-" => None,
+                _ => Some(extra_in.as_slice())
+            };
+            let (secret_nonce, public_nonce) = musig_nonce_gen(&rand, private_key, &public_key, aggregate_key.as_ref(), message, extra_in, &secp256k1)?;
+            assert_eq!(hex::encode(secret_nonce.to_bytes(&secp256k1)?), fields[6]);
+            assert_eq!(hex::encode(public_nonce.to_bytes(&secp256k1)?), fields[7]);
+            count += 1;
+        }
+        assert_eq!(count, 4);
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use crate::types::{Scalar, KeyAggContext, MusigAggregateNonce, SchnorrSignature, EllipticCurve, Errors};
+use crate::utils::musig_session;
+
+/// Combines the partial signatures into the final signature (R, s) (BIP327 PartialSigAgg),
+/// s = s1 + ... + su + e * g * tacc accounts for the tweaks. The result is a BIP340 signature
+/// under the x only aggregate key.
+pub fn musig_partial_sig_agg(partial_signatures: &[Scalar], key_agg: &KeyAggContext, aggregate_nonce: &MusigAggregateNonce,
+                             message: &[u8], curve: &EllipticCurve) -> Result<SchnorrSignature, Errors> {
+    let session = musig_session(key_agg, aggregate_nonce, message, curve)?;
+    let g = key_agg.key_sign(curve);
+    let s = partial_signatures.iter().fold(session.challenge * g * key_agg.tacc, |s, partial| s + *partial);
+    Ok(SchnorrSignature::new(session.nonce_point.x, s.value()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, ECpoint, SignatureVerification};
+    use crate::ec_math::{scalar_mult_base, tap_tweak, taproot_tweak_public_key};
+    use crate::utils::{musig_key_agg, musig_apply_tweak, musig_nonce_gen, musig_nonce_agg, musig_partial_sign, schnorr_verify};
+
+    #[test]
+    fn test_musig_partial_sig_agg() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // BIP327 signature aggregation vectors, partial signatures not below n are rejected when parsed
+        let vectors = include_str!("../../test_vectors/bip327_sig_agg.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let mut keys = Vec::new();
+            for key in fields[0].split(' ') {
+                keys.push(ECpoint::from_sec1(&hex::decode(key).unwrap(), &secp256k1)?);
+            }
+            let mut key_agg = musig_key_agg(&keys, &secp256k1)?;
+            if !fields[1].is_empty() {
+                for (tweak, x_only) in fields[1].split(' ').zip(fields[2].split(' ')) {
+                    let mut bytes = [0u8; 32];
+                    bytes.copy_from_slice(&hex::decode(tweak).unwrap());
+                    key_agg = musig_apply_tweak(&key_agg, &bytes, x_only == "1", &secp256k1)?;
+                }
+            }
+            let mut bytes = [0u8; 66];
+            bytes.copy_from_slice(&hex::decode(fields[3]).unwrap());
+            let aggregate_nonce = MusigAggregateNonce::from_bytes(&bytes, &secp256k1)?;
+            let message = hex::decode(fields[4]).unwrap();
+            let partials: Vec<Result<Scalar, Errors>> = fields[5].split(' ').map(|partial| {
+                let mut bytes = [0u8; 32];
+                bytes.copy_from_slice(&hex::decode(partial).unwrap());
+                Scalar::from_bytes(&bytes, &secp256k1)
+            }).collect();
+            match fields[7].parse::<usize>() {
+                Ok(invalid) => assert!(partials[invalid].is_err(), "line {}", line),
+                Err(_) => {
+                    let partials: Result<Vec<Scalar>, Errors> = partials.into_iter().collect();
+                    let signature = musig_partial_sig_agg(&partials?, &key_agg, &aggregate_nonce, &message, &secp256k1)?;
+                    assert_eq!(hex::encode(signature.to_bytes()), fields[6], "line {}", line);
+                    let key = key_agg.x_only_public_key(&secp256k1)?;
+                    assert_eq!(schnorr_verify(&key, &message, &signature, &secp256k1)?, SignatureVerification::Successful);
+                }
+            }
+            count += 1;
+        }
+        assert_eq!(count, 5);
+
+        // two signers spend the key path of a taproot output whose internal key is their aggregate key
+        let private_keys = [Scalar::new(U256::from(3), &secp256k1), Scalar::new(U256::from(7), &secp256k1)];
+        let mut keys = Vec::new();
+        for private_key in private_keys.iter() {
+            keys.push(scalar_mult_base(private_key.value(), &secp256k1)?);
+        }
+        let key_agg = musig_key_agg(&keys, &secp256k1)?;
+        let internal_key = key_agg.x_only_public_key(&secp256k1)?;
+        let key_agg = musig_apply_tweak(&key_agg, &tap_tweak(&internal_key, None, &secp256k1)?.to_bytes(), true, &secp256k1)?;
+        let (output_key, _) = taproot_tweak_public_key(&internal_key, None, &secp256k1)?;
+        assert_eq!(key_agg.x_only_public_key(&secp256k1)?, output_key);
+        let message = b"taproot key path";
+        let mut secret_nonces = Vec::new();
+        let mut public_nonces = Vec::new();
+        for (i, key) in keys.iter().enumerate() {
+            let (secret_nonce, public_nonce) = musig_nonce_gen(&[i as u8; 32], Some(private_keys[i]), key, Some(&output_key), Some(message), None, &secp256k1)?;
+            secret_nonces.push(secret_nonce);
+            public_nonces.push(public_nonce);
+        }
+        let aggregate_nonce = musig_nonce_agg(&public_nonces, &secp256k1)?;
+        let mut partials = Vec::new();
+        for (secret_nonce, private_key) in secret_nonces.into_iter().zip(private_keys.iter()) {
+            partials.push(musig_partial_sign(secret_nonce, *private_key, &key_agg, &aggregate_nonce, message, &secp256k1)?);
+        }
+        let signature = musig_partial_sig_agg(&partials, &key_agg, &aggregate_nonce, message, &secp256k1)?;
+        assert_eq!(schnorr_verify(&output_key, message, &signature, &secp256k1)?, SignatureVerification::Successful);
+        Ok(())
+    }
+}
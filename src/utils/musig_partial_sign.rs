@@ -0,0 +1,89 @@
+use crate::types::{Scalar, KeyAggContext, MusigSecretNonce, MusigPublicNonce, MusigAggregateNonce, SignatureVerification, EllipticCurve, Errors};
+use crate::ec_math::scalar_mult_base;
+use crate::utils::{musig_session, musig_partial_verify};
+
+/// Creates the partial signature s = k1 + b * k2 + e * a * d of one MuSig2 signer (BIP327 Sign), the second round.
+/// The secret nonce is consumed, the nonces and the key are negated as needed for R and Q with an even y.
+/// Fails if the nonce belongs to another key, the key was not aggregated or the nonce was zeroed.
+pub fn musig_partial_sign(secret_nonce: MusigSecretNonce, private_key: Scalar, key_agg: &KeyAggContext, aggregate_nonce: &MusigAggregateNonce,
+                          message: &[u8], curve: &EllipticCurve) -> Result<Scalar, Errors> {
+    let MusigSecretNonce {k1, k2, public_key} = secret_nonce;
+    if k1.is_zero() || k2.is_zero() {
+        return Err(Errors::InvalidSecretNonce);
+    }
+    if private_key.is_zero() {
+        return Err(Errors::ZeroPrivateKey);
+    }
+    if scalar_mult_base(private_key.value(), curve)? != public_key {
+        return Err(Errors::SecretNonceKeyMismatch);
+    }
+    let a = key_agg.coefficient(&public_key)?;
+    let session = musig_session(key_agg, aggregate_nonce, message, curve)?;
+    let (k1_signed, k2_signed) = match session.nonce_point.y.bit(0) {
+        true => (-k1, -k2),
+        false => (k1, k2)
+    };
+    let g = key_agg.key_sign(curve);
+    let d = g * key_agg.gacc * private_key;
+    let s = k1_signed + session.nonce_coefficient * k2_signed + session.challenge * a * d;
+    // a faulty computation must not leave with the signature
+    let public_nonce = MusigPublicNonce::new(scalar_mult_base(k1.value(), curve)?, scalar_mult_base(k2.value(), curve)?);
+    match musig_partial_verify(&s, &public_nonce, &public_key, key_agg, aggregate_nonce, message, curve)? {
+        SignatureVerification::Successful => Ok(s),
+        SignatureVerification::Failed => Err(Errors::InvalidSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ECpoint;
+    use crate::utils::{musig_key_agg, musig_nonce_agg};
+
+    #[test]
+    fn test_musig_partial_sign() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // signing rows of the BIP327 sign/verify vectors
+        let vectors = include_str!("../../test_vectors/bip327_sign_verify.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1).filter(|line| line.starts_with("sign,")) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let private_key = Scalar::new(fields[1].parse().unwrap(), &secp256k1);
+            let mut bytes = [0u8; 97];
+            bytes.copy_from_slice(&hex::decode(fields[2]).unwrap());
+            let secret_nonce = MusigSecretNonce::from_bytes(&bytes, &secp256k1)?;
+            let keys: Result<Vec<ECpoint>, Errors> = fields[3].split(' ')
+                .map(|key| ECpoint::from_sec1(&hex::decode(key).unwrap(), &secp256k1)).collect();
+            let mut bytes = [0u8; 66];
+            bytes.copy_from_slice(&hex::decode(fields[5]).unwrap());
+            let aggregate_nonce = MusigAggregateNonce::from_bytes(&bytes, &secp256k1);
+            let message = hex::decode(fields[6]).unwrap();
+            match fields[9] {
+                // either the signer's key is missing from the list or another key is invalid
+                "pubkey" => if let Ok(keys) = keys {
+                    assert_eq!(musig_partial_sign(secret_nonce, private_key, &musig_key_agg(&keys, &secp256k1)?, &aggregate_nonce?, &message, &secp256k1),
+                               Err(Errors::PublicKeyNotInList));
+                },
+                "aggnonce" => assert!(aggregate_nonce.is_err(), "line {}", line),
+                "secnonce" => assert_eq!(musig_partial_sign(secret_nonce, private_key, &musig_key_agg(&keys?, &secp256k1)?, &aggregate_nonce?, &message, &secp256k1),
+                                         Err(Errors::InvalidSecretNonce)),
+                _ => {
+                    // the listed public nonces add up to the aggregate nonce of the vector
+                    let nonces: Result<Vec<MusigPublicNonce>, Errors> = fields[4].split(' ').map(|nonce| {
+                        let mut bytes = [0u8; 66];
+                        bytes.copy_from_slice(&hex::decode(nonce).unwrap());
+                        MusigPublicNonce::from_bytes(&bytes, &secp256k1)
+                    }).collect();
+                    let aggregate_nonce = aggregate_nonce?;
+                    assert_eq!(musig_nonce_agg(&nonces?, &secp256k1)?, aggregate_nonce);
+                    let key_agg = musig_key_agg(&keys?, &secp256k1)?;
+                    let s = musig_partial_sign(secret_nonce, private_key, &key_agg, &aggregate_nonce, &message, &secp256k1)?;
+                    assert_eq!(hex::encode(s.to_bytes()), fields[8], "line {}", line);
+                }
+            }
+            count += 1;
+        }
+        assert_eq!(count, 12);
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use crate::types::{Scalar, Point, Points, ECpoint, KeyAggContext, MusigPublicNonce, MusigAggregateNonce, SignatureVerification, EllipticCurve, Errors};
+use crate::ec_math::multi_scalar_mult;
+use crate::utils::musig_session;
+
+/// Verifies the partial signature of the signer with `public_key` and `public_nonce` (BIP327 PartialSigVerify):
+/// s * G = Re + e * a * g * P with Re = R1 + b * R2 of the signer, negated if the final R has an odd y.
+/// Lets the aggregator find a misbehaving signer. Returns PublicKeyNotInList if the key was not aggregated.
+pub fn musig_partial_verify(partial_signature: &Scalar, public_nonce: &MusigPublicNonce, public_key: &ECpoint, key_agg: &KeyAggContext,
+                            aggregate_nonce: &MusigAggregateNonce, message: &[u8], curve: &EllipticCurve) -> Result<SignatureVerification, Errors> {
+    let a = key_agg.coefficient(public_key)?;
+    let session = musig_session(key_agg, aggregate_nonce, message, curve)?;
+    let nonce_sign = match session.nonce_point.y.bit(0) {
+        true => -Scalar::one(curve),
+        false => Scalar::one(curve)
+    };
+    let g = key_agg.key_sign(curve);
+    let key_factor = session.challenge * a * g * key_agg.gacc;
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    // s * G - e * a * g * P - Re has to be the point at infinity
+    let check = multi_scalar_mult(&[
+        (partial_signature.value(), G),
+        ((-key_factor).value(), (*public_key).into()),
+        ((-nonce_sign).value(), public_nonce.r1.into()),
+        ((-(nonce_sign * session.nonce_coefficient)).value(), public_nonce.r2.into())
+    ], curve)?;
+    match check {
+        ECpoint::Infinity => Ok(SignatureVerification::Successful),
+        ECpoint::OnCurve(_) => Ok(SignatureVerification::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{musig_key_agg, musig_nonce_agg};
+
+    fn parse_nonces(text: &str, curve: &EllipticCurve) -> Result<Vec<MusigPublicNonce>, Errors> {
+        text.split(' ').map(|nonce| {
+            let mut bytes = [0u8; 66];
+            bytes.copy_from_slice(&hex::decode(nonce).unwrap());
+            MusigPublicNonce::from_bytes(&bytes, curve)
+        }).collect()
+    }
+
+    #[test]
+    fn test_musig_partial_verify() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // all rows of the BIP327 sign/verify vectors: the valid signatures verify, the rest fails
+        let vectors = include_str!("../../test_vectors/bip327_sign_verify.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            if fields[8].is_empty() {
+                continue;
+            }
+            let keys: Result<Vec<ECpoint>, Errors> = fields[3].split(' ')
+                .map(|key| ECpoint::from_sec1(&hex::decode(key).unwrap(), &secp256k1)).collect();
+            let nonces = parse_nonces(fields[4], &secp256k1);
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&hex::decode(fields[8]).unwrap());
+            let s = Scalar::from_bytes(&bytes, &secp256k1);
+            let message = hex::decode(fields[6]).unwrap();
+            let signer: usize = fields[7].parse().unwrap();
+            match fields[9] {
+                "pubkey" => assert!(keys.is_err(), "line {}", line),
+                "pubnonce" => assert!(nonces.is_err(), "line {}", line),
+                "sig" => assert!(s.is_err(), "line {}", line),
+                _ => {
+                    let (keys, nonces, s) = (keys?, nonces?, s?);
+                    let key_agg = musig_key_agg(&keys, &secp256k1)?;
+                    let aggregate_nonce = musig_nonce_agg(&nonces, &secp256k1)?;
+                    let expected = match fields[9] {
+                        "verify" => SignatureVerification::Failed,
+                        _ => SignatureVerification::Successful
+                    };
+                    assert_eq!(musig_partial_verify(&s, &nonces[signer], &keys[signer], &key_agg, &aggregate_nonce, &message, &secp256k1)?, expected, "line {}", line);
+                }
+            }
+            count += 1;
+        }
+        assert_eq!(count, 11);
+        Ok(())
+    }
+}
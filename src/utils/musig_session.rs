@@ -0,0 +1,58 @@
+use crate::types::{U256, Scalar, Point, ECpoint, KeyAggContext, MusigAggregateNonce, MusigSession, EllipticCurve, Errors};
+use crate::ec_math::multi_scalar_mult;
+use crate::utils::tagged_hash;
+
+/// Derives the session values of a MuSig2 signing session (BIP327 GetSessionValues),
+/// if R1 + b * R2 is the point at infinity G is used for R.
+pub fn musig_session(key_agg: &KeyAggContext, aggregate_nonce: &MusigAggregateNonce, message: &[u8], curve: &EllipticCurve) -> Result<MusigSession, Errors> {
+    let key = key_agg.x_only_public_key(curve)?.to_bytes();
+    let b = Scalar::from_bytes_reduced(&tagged_hash("MuSig/noncecoef", &[&aggregate_nonce.to_bytes(curve), &key, message]), curve);
+    #[allow(non_snake_case)]
+    let R = match multi_scalar_mult(&[(U256::one(), aggregate_nonce.r1.into()), (b.value(), aggregate_nonce.r2.into())], curve)? {
+        ECpoint::OnCurve(point) => point,
+        ECpoint::Infinity => Point::from(curve.g)
+    };
+    let mut r = [0u8; 32];
+    R.x.to_big_endian(&mut r);
+    let e = Scalar::from_bytes_reduced(&tagged_hash("BIP0340/challenge", &[&r, &key, message]), curve);
+    Ok(MusigSession {nonce_coefficient: b, nonce_point: R, challenge: e})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::musig_key_agg;
+
+    #[test]
+    fn test_musig_session() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // the x of R is the first half of the aggregated signatures of the BIP327 vectors
+        let vectors = include_str!("../../test_vectors/bip327_sig_agg.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            if !fields[1].is_empty() || !fields[7].is_empty() {
+                continue;
+            }
+            let mut keys = Vec::new();
+            for key in fields[0].split(' ') {
+                keys.push(ECpoint::from_sec1(&hex::decode(key).unwrap(), &secp256k1)?);
+            }
+            let key_agg = musig_key_agg(&keys, &secp256k1)?;
+            let mut bytes = [0u8; 66];
+            bytes.copy_from_slice(&hex::decode(fields[3]).unwrap());
+            let aggregate_nonce = MusigAggregateNonce::from_bytes(&bytes, &secp256k1)?;
+            let session = musig_session(&key_agg, &aggregate_nonce, &hex::decode(fields[4]).unwrap(), &secp256k1)?;
+            let mut r = [0u8; 32];
+            session.nonce_point.x.to_big_endian(&mut r);
+            assert_eq!(hex::encode(r), fields[6][..64], "line {}", line);
+            count += 1;
+        }
+        assert_eq!(count, 2);
+        // an aggregate nonce of two points at infinity gives G
+        let key_agg = musig_key_agg(&[ECpoint::OnCurve(Point::from(secp256k1.g))], &secp256k1)?;
+        let aggregate_nonce = MusigAggregateNonce::new(ECpoint::Infinity, ECpoint::Infinity);
+        assert_eq!(musig_session(&key_agg, &aggregate_nonce, b"", &secp256k1)?.nonce_point, Point::from(secp256k1.g));
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::types::{ECpoint, EllipticCurve};
+use crate::utils::{hash160, base58check_encode};
+
+/// Returns the mainnet pay-to-public-key-hash address (starting with 1) of `public_key`,
+/// hashing its compressed or uncompressed SEC1 encoding.
+pub fn p2pkh_address(public_key: &ECpoint, compressed: bool, curve: &EllipticCurve) -> String {
+    let mut payload = vec![0x00];
+    payload.extend_from_slice(&hash160(&public_key.to_sec1(compressed, curve)));
+    base58check_encode(&payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Point;
+
+    #[test]
+    fn test_p2pkh_address() {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // private key 1
+        let g = ECpoint::OnCurve(Point::from(secp256k1.g));
+        assert_eq!(p2pkh_address(&g, true, &secp256k1), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+        assert_eq!(p2pkh_address(&g, false, &secp256k1), "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm");
+    }
+}
@@ -0,0 +1,67 @@
+use crate::types::{Scalar, RecoverableSignature, EllipticCurve, ECpoint, Point, Points, Errors, MessageDigest};
+use crate::utils::hash_message;
+use crate::ec_math::{lift_x, multi_scalar_mult};
+
+/// Returns the public key that created `signature` over `message`.
+/// The nonce point R is rebuilt from r and the recovery id, then Q = r^-1 * (s * R - z * G).
+pub fn recover_public_key(message: &[u8], signature: &RecoverableSignature, digest: MessageDigest, curve: &EllipticCurve) -> Result<ECpoint, Errors> {
+    let RecoverableSignature { signature, recovery_id } = *signature;
+    if recovery_id > 3 {
+        return Err(Errors::InvalidRecoveryId(recovery_id));
+    }
+    if signature.r.is_zero() || signature.s.is_zero() {
+        return Err(Errors::InvalidSignature);
+    }
+    let x = match recovery_id & 2 != 0 {
+        true => match signature.r.value().overflowing_add(curve.n) {
+            (x, false) if x < curve.p => x,
+            _ => return Err(Errors::InvalidRecoveryId(recovery_id))
+        },
+        false => signature.r.value()
+    };
+    #[allow(non_snake_case)]
+    let R = lift_x(x, recovery_id & 1 != 0, curve)?;
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    let z = Scalar::new(hash_message(message, digest, curve.n.bits())?, curve);
+    let r_inv = signature.r.inverse_vartime()?;
+    let u1 = -z * r_inv;
+    let u2 = signature.s * r_inv;
+    match multi_scalar_mult(&[(u1.value(), G), (u2.value(), R.into())], curve)? {
+        ECpoint::Infinity => Err(Errors::InvalidSignature),
+        public_key => Ok(public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Signature, SignatureVerification};
+    use crate::utils::{make_keypair, sign_message_recoverable, verify_signature};
+
+    #[test]
+    fn test_recover_public_key() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let (a, A) = make_keypair(&secp256k1)?;
+        let message = b"This is a test";
+        for digest in [MessageDigest::Sha256, MessageDigest::DoubleSha256, MessageDigest::Sha512].iter() {
+            let sig = sign_message_recoverable(a, message, *digest, &secp256k1)?;
+            assert_eq!(recover_public_key(message, &sig, *digest, &secp256k1)?, A);
+            assert_eq!(verify_signature(A, message, &sig.signature, *digest, &secp256k1)?, SignatureVerification::Successful);
+            // the other parity gives a different key, an other message too
+            let flipped = RecoverableSignature::new(sig.signature, sig.recovery_id ^ 1);
+            assert_ne!(recover_public_key(message, &flipped, *digest, &secp256k1)?, A);
+            assert_ne!(recover_public_key(b"other message", &sig, *digest, &secp256k1)?, A);
+        }
+        let sig = sign_message_recoverable(a, message, MessageDigest::Sha256, &secp256k1)?;
+        // r + n is above p for almost every r of secp256k1
+        let overflowing = RecoverableSignature::new(sig.signature, sig.recovery_id | 2);
+        assert_eq!(recover_public_key(message, &overflowing, MessageDigest::Sha256, &secp256k1), Err(Errors::InvalidRecoveryId(sig.recovery_id | 2)));
+        let bad_id = RecoverableSignature::new(sig.signature, 4);
+        assert_eq!(recover_public_key(message, &bad_id, MessageDigest::Sha256, &secp256k1), Err(Errors::InvalidRecoveryId(4)));
+        let zero_s = RecoverableSignature::new(Signature::new(sig.signature.r, Scalar::zero(&secp256k1)), 0);
+        assert_eq!(recover_public_key(message, &zero_s, MessageDigest::Sha256, &secp256k1), Err(Errors::InvalidSignature));
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::types::{Scalar, ECpoint, SchnorrPreSignature, SchnorrSignature, Errors};
+
+/// Completes a Schnorr pre-signature with the secret t of its adaptor point into the BIP340 signature
+/// (x(R), s' + t), t is subtracted instead if R has an odd y. The result only verifies for the right t.
+pub fn schnorr_adaptor_complete(pre_signature: &SchnorrPreSignature, adaptor_secret: Scalar) -> Result<SchnorrSignature, Errors> {
+    #[allow(non_snake_case)]
+    let R = match pre_signature.r {
+        ECpoint::Infinity => return Err(Errors::PointAtInfinity),
+        ECpoint::OnCurve(point) => point
+    };
+    let t = match R.y.bit(0) {
+        true => -adaptor_secret,
+        false => adaptor_secret
+    };
+    Ok(SchnorrSignature::new(R.x, (pre_signature.s + t).value()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, XOnlyPublicKey, SignatureVerification, EllipticCurve};
+    use crate::ec_math::scalar_mult_base;
+    use crate::utils::{schnorr_adaptor_sign, schnorr_verify};
+
+    #[test]
+    fn test_schnorr_adaptor_complete() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let private_key = Scalar::new(U256::from(7), &secp256k1);
+        let public_key = XOnlyPublicKey::from_point(&scalar_mult_base(private_key.value(), &secp256k1)?, &secp256k1)?;
+        let t = Scalar::new(U256::from(13), &secp256k1);
+        #[allow(non_snake_case)]
+        let T = scalar_mult_base(t.value(), &secp256k1)?;
+        let pre_signature = schnorr_adaptor_sign(private_key, b"swap", &T, &[0u8; 32], &secp256k1)?;
+        let signature = schnorr_adaptor_complete(&pre_signature, t)?;
+        assert_eq!(signature.r, pre_signature.r.to_finite_point().x);
+        assert_eq!(schnorr_verify(&public_key, b"swap", &signature, &secp256k1)?, SignatureVerification::Successful);
+        let wrong = schnorr_adaptor_complete(&pre_signature, t + Scalar::one(&secp256k1))?;
+        assert_eq!(schnorr_verify(&public_key, b"swap", &wrong, &secp256k1)?, SignatureVerification::Failed);
+        let infinite = SchnorrPreSignature::new(ECpoint::Infinity, pre_signature.s);
+        assert_eq!(schnorr_adaptor_complete(&infinite, t), Err(Errors::PointAtInfinity));
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::types::{Scalar, ECpoint, SchnorrPreSignature, SchnorrSignature, EllipticCurve, Errors};
+use crate::ec_math::scalar_mult_base;
+
+/// Recovers the secret t of the adaptor point T from a pre-signature and the signature completed from it:
+/// t = s - s', negated if R has an odd y. Returns AdaptorMismatch if the signature was not completed
+/// from this pre-signature with the secret of T.
+pub fn schnorr_adaptor_extract(pre_signature: &SchnorrPreSignature, signature: &SchnorrSignature, adaptor_point: &ECpoint, curve: &EllipticCurve) -> Result<Scalar, Errors> {
+    #[allow(non_snake_case)]
+    let R = match pre_signature.r {
+        ECpoint::Infinity => return Err(Errors::PointAtInfinity),
+        ECpoint::OnCurve(point) => point
+    };
+    if signature.r != R.x {
+        return Err(Errors::AdaptorMismatch);
+    }
+    let t = Scalar::new(signature.s, curve) - pre_signature.s;
+    let t = match R.y.bit(0) {
+        true => -t,
+        false => t
+    };
+    match scalar_mult_base(t.value(), curve)? == *adaptor_point {
+        true => Ok(t),
+        false => Err(Errors::AdaptorMismatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::traits::GenRandU256;
+    use crate::utils::{schnorr_adaptor_sign, schnorr_adaptor_complete, schnorr_sign};
+
+    #[test]
+    fn test_schnorr_adaptor_extract() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let private_key = Scalar::new(U256::from(7), &secp256k1);
+        let mut rng = rand::thread_rng();
+        for _ in 0..4 {
+            let t = Scalar::new(rng.gen_u256_range(&U256::one(), &secp256k1.n), &secp256k1);
+            #[allow(non_snake_case)]
+            let T = scalar_mult_base(t.value(), &secp256k1)?;
+            let pre_signature = schnorr_adaptor_sign(private_key, b"swap", &T, &[0u8; 32], &secp256k1)?;
+            let signature = schnorr_adaptor_complete(&pre_signature, t)?;
+            assert_eq!(schnorr_adaptor_extract(&pre_signature, &signature, &T, &secp256k1)?, t);
+            // a signature completed with another secret or with another nonce gives nothing away
+            let wrong = schnorr_adaptor_complete(&pre_signature, t + Scalar::one(&secp256k1))?;
+            assert_eq!(schnorr_adaptor_extract(&pre_signature, &wrong, &T, &secp256k1), Err(Errors::AdaptorMismatch));
+            let plain = schnorr_sign(private_key, b"swap", &[0u8; 32], &secp256k1)?;
+            assert_eq!(schnorr_adaptor_extract(&pre_signature, &plain, &T, &secp256k1), Err(Errors::AdaptorMismatch));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use crate::types::{Scalar, ECpoint, SchnorrPreSignature, EllipticCurve, Errors};
+use crate::utils::tagged_hash;
+use crate::utils::schnorr_sign::{bip340_signing_key, bip340_masked_key};
+use crate::ec_math::{point_add, scalar_mult_base};
+
+/// Creates a Schnorr pre-signature of `message` bound to the adaptor point T = t * G. Like `schnorr_sign`,
+/// but the final nonce point is R = k * G + T, so the challenge already commits to T and only adding t
+/// gives a valid BIP340 signature. k is negated if R has an odd y, the key if d * G has one.
+/// The nonce also depends on T, a pre-signature never shares its nonce with a plain signature.
+pub fn schnorr_adaptor_sign(private_key: Scalar, message: &[u8], adaptor_point: &ECpoint, aux_rand: &[u8; 32], curve: &EllipticCurve) -> Result<SchnorrPreSignature, Errors> {
+    let (d, public_key) = bip340_signing_key(private_key, curve)?;
+    if *adaptor_point == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let k = Scalar::from_bytes_reduced(&tagged_hash("SchnorrAdaptor/nonce", &[&bip340_masked_key(d, aux_rand), &adaptor_point.to_sec1(true, curve), &public_key.to_bytes(), message]), curve);
+    if k.is_zero() {
+        return Err(Errors::ZeroNonce);
+    }
+    #[allow(non_snake_case)]
+    let R = point_add(&scalar_mult_base(k.value(), curve)?.into(), &(*adaptor_point).into(), curve)?;
+    if R == ECpoint::Infinity {
+        return Err(Errors::PointAtInfinity);
+    }
+    let k = match R.to_finite_point().y.bit(0) {
+        true => -k,
+        false => k
+    };
+    let mut r_bytes = [0u8; 32];
+    R.to_finite_point().x.to_big_endian(&mut r_bytes);
+    let e = Scalar::from_bytes_reduced(&tagged_hash("BIP0340/challenge", &[&r_bytes, &public_key.to_bytes(), message]), curve);
+    Ok(SchnorrPreSignature::new(R, k + e * d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, XOnlyPublicKey, SignatureVerification};
+    use crate::utils::{schnorr_adaptor_verify, schnorr_adaptor_complete, schnorr_sign, schnorr_verify};
+
+    #[test]
+    fn test_schnorr_adaptor_sign() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let private_key = Scalar::new(U256::from(3), &secp256k1);
+        let public_key = XOnlyPublicKey::from_point(&scalar_mult_base(private_key.value(), &secp256k1)?, &secp256k1)?;
+        let t = Scalar::new(U256::from(11), &secp256k1);
+        #[allow(non_snake_case)]
+        let T = scalar_mult_base(t.value(), &secp256k1)?;
+        // both parities of R have to come out right
+        let mut parities = [false, false];
+        for i in 0..8u8 {
+            let pre_signature = schnorr_adaptor_sign(private_key, b"swap", &T, &[i; 32], &secp256k1)?;
+            parities[pre_signature.r.to_finite_point().y.bit(0) as usize] = true;
+            assert_eq!(schnorr_adaptor_verify(&public_key, b"swap", &T, &pre_signature, &secp256k1)?, SignatureVerification::Successful);
+            let signature = schnorr_adaptor_complete(&pre_signature, t)?;
+            assert_eq!(schnorr_verify(&public_key, b"swap", &signature, &secp256k1)?, SignatureVerification::Successful);
+            // the pre-signature itself is not a valid signature
+            let unadapted = schnorr_adaptor_complete(&pre_signature, Scalar::zero(&secp256k1))?;
+            assert_eq!(schnorr_verify(&public_key, b"swap", &unadapted, &secp256k1)?, SignatureVerification::Failed);
+        }
+        assert_eq!(parities, [true, true]);
+        let pre_signature = schnorr_adaptor_sign(private_key, b"swap", &T, &[0u8; 32], &secp256k1)?;
+        assert_ne!(pre_signature.r.to_finite_point().x, schnorr_sign(private_key, b"swap", &[0u8; 32], &secp256k1)?.r);
+        assert_eq!(schnorr_adaptor_sign(Scalar::zero(&secp256k1), b"swap", &T, &[0u8; 32], &secp256k1), Err(Errors::ZeroPrivateKey));
+        assert_eq!(schnorr_adaptor_sign(private_key, b"swap", &ECpoint::Infinity, &[0u8; 32], &secp256k1), Err(Errors::PointAtInfinity));
+        Ok(())
+    }
+}
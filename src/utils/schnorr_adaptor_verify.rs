@@ -0,0 +1,56 @@
+use crate::types::{Scalar, Point, Points, ECpoint, SchnorrPreSignature, XOnlyPublicKey, SignatureVerification, EllipticCurve, Errors};
+use crate::utils::tagged_hash;
+use crate::ec_math::{multi_scalar_mult, point_add, point_neg};
+
+/// Verifies a Schnorr pre-signature for the adaptor point T: s' * G - e * P must be R - T,
+/// or T - R if R has an odd y. If it holds, adding the secret of T yields a valid BIP340 signature.
+pub fn schnorr_adaptor_verify(public_key: &XOnlyPublicKey, message: &[u8], adaptor_point: &ECpoint, pre_signature: &SchnorrPreSignature, curve: &EllipticCurve) -> Result<SignatureVerification, Errors> {
+    #[allow(non_snake_case)]
+    let R = pre_signature.r;
+    if R == ECpoint::Infinity || *adaptor_point == ECpoint::Infinity {
+        return Ok(SignatureVerification::Failed);
+    }
+    let mut r_bytes = [0u8; 32];
+    R.to_finite_point().x.to_big_endian(&mut r_bytes);
+    let e = Scalar::from_bytes_reduced(&tagged_hash("BIP0340/challenge", &[&r_bytes, &public_key.to_bytes(), message]), curve);
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    let nonce = multi_scalar_mult(&[(pre_signature.s.value(), G), ((-e).value(), public_key.to_point().into())], curve)?;
+    let expected = point_add(&R.into(), &point_neg(&(*adaptor_point).into(), curve)?.into(), curve)?;
+    let expected = match R.to_finite_point().y.bit(0) {
+        true => point_neg(&expected.into(), curve)?,
+        false => expected
+    };
+    match nonce == expected {
+        true => Ok(SignatureVerification::Successful),
+        false => Ok(SignatureVerification::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::ec_math::scalar_mult_base;
+    use crate::utils::schnorr_adaptor_sign;
+
+    #[test]
+    fn test_schnorr_adaptor_verify() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let private_key = Scalar::new(U256::from(5), &secp256k1);
+        let public_key = XOnlyPublicKey::from_point(&scalar_mult_base(private_key.value(), &secp256k1)?, &secp256k1)?;
+        #[allow(non_snake_case)]
+        let T = scalar_mult_base(U256::from(11), &secp256k1)?;
+        let pre_signature = schnorr_adaptor_sign(private_key, b"swap", &T, &[0u8; 32], &secp256k1)?;
+        assert_eq!(schnorr_adaptor_verify(&public_key, b"swap", &T, &pre_signature, &secp256k1)?, SignatureVerification::Successful);
+        assert_eq!(schnorr_adaptor_verify(&public_key, b"other", &T, &pre_signature, &secp256k1)?, SignatureVerification::Failed);
+        let other_point = scalar_mult_base(U256::from(12), &secp256k1)?;
+        assert_eq!(schnorr_adaptor_verify(&public_key, b"swap", &other_point, &pre_signature, &secp256k1)?, SignatureVerification::Failed);
+        let other_key = XOnlyPublicKey::from_point(&scalar_mult_base(U256::from(6), &secp256k1)?, &secp256k1)?;
+        assert_eq!(schnorr_adaptor_verify(&other_key, b"swap", &T, &pre_signature, &secp256k1)?, SignatureVerification::Failed);
+        let tampered = SchnorrPreSignature::new(pre_signature.r, pre_signature.s + Scalar::one(&secp256k1));
+        assert_eq!(schnorr_adaptor_verify(&public_key, b"swap", &T, &tampered, &secp256k1)?, SignatureVerification::Failed);
+        assert_eq!(schnorr_adaptor_verify(&public_key, b"swap", &ECpoint::Infinity, &pre_signature, &secp256k1)?, SignatureVerification::Failed);
+        Ok(())
+    }
+}
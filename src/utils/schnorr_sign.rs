@@ -0,0 +1,79 @@
+use crate::types::{Scalar, SchnorrSignature, XOnlyPublicKey, EllipticCurve, Errors};
+use crate::utils::tagged_hash;
+use crate::ec_math::scalar_mult_base;
+
+/// Signs `message` with `private_key` following BIP340. The nonce is derived from the key,
+/// the message and `aux_rand` (fresh randomness is recommended, zeros are still safe).
+/// The key and the nonce are negated when needed so that both points have an even y.
+pub fn schnorr_sign(private_key: Scalar, message: &[u8], aux_rand: &[u8; 32], curve: &EllipticCurve) -> Result<SchnorrSignature, Errors> {
+    let (d, public_key) = bip340_signing_key(private_key, curve)?;
+    let k = Scalar::from_bytes_reduced(&tagged_hash("BIP0340/nonce", &[&bip340_masked_key(d, aux_rand), &public_key.to_bytes(), message]), curve);
+    if k.is_zero() {
+        return Err(Errors::ZeroNonce);
+    }
+    #[allow(non_snake_case)]
+    let R = scalar_mult_base(k.value(), curve)?;
+    let k = match R.to_finite_point().y.bit(0) {
+        true => -k,
+        false => k
+    };
+    let r = R.to_finite_point().x;
+    let mut r_bytes = [0u8; 32];
+    r.to_big_endian(&mut r_bytes);
+    let e = Scalar::from_bytes_reduced(&tagged_hash("BIP0340/challenge", &[&r_bytes, &public_key.to_bytes(), message]), curve);
+    Ok(SchnorrSignature::new(r, (k + e * d).value()))
+}
+
+/// Returns the BIP340 signing key d, the private key negated if its point has an odd y, and the x only public key.
+pub(crate) fn bip340_signing_key(private_key: Scalar, curve: &EllipticCurve) -> Result<(Scalar, XOnlyPublicKey), Errors> {
+    if private_key.is_zero() {
+        return Err(Errors::ZeroPrivateKey);
+    }
+    let point = scalar_mult_base(private_key.value(), curve)?;
+    let d = match point.to_finite_point().y.bit(0) {
+        true => -private_key,
+        false => private_key
+    };
+    Ok((d, XOnlyPublicKey::from_point(&point, curve)?))
+}
+
+/// Returns d masked with the hash of `aux_rand`, the key material the BIP340 nonce is derived from.
+pub(crate) fn bip340_masked_key(d: Scalar, aux_rand: &[u8; 32]) -> [u8; 32] {
+    let mut t = d.to_bytes();
+    for (byte, mask) in t.iter_mut().zip(tagged_hash("BIP0340/aux", &[aux_rand]).iter()) {
+        *byte ^= mask;
+    }
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+
+    #[test]
+    fn test_schnorr_sign() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // rows of the official BIP340 vectors that have a secret key
+        let vectors = include_str!("../../test_vectors/bip340.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.splitn(8, ',').collect();
+            if fields[1].is_empty() {
+                continue;
+            }
+            let private_key = Scalar::new(fields[1].parse::<U256>().unwrap(), &secp256k1);
+            let public_key = XOnlyPublicKey::from_point(&scalar_mult_base(private_key.value(), &secp256k1)?, &secp256k1)?;
+            assert_eq!(hex::encode_upper(public_key.to_bytes()), fields[2], "public key of vector {}", fields[0]);
+            let mut aux_rand = [0u8; 32];
+            aux_rand.copy_from_slice(&hex::decode(fields[3]).unwrap());
+            let message = hex::decode(fields[4]).unwrap();
+            let signature = schnorr_sign(private_key, &message, &aux_rand, &secp256k1)?;
+            assert_eq!(hex::encode_upper(signature.to_bytes()), fields[5], "signature of vector {}", fields[0]);
+            count += 1;
+        }
+        assert_eq!(count, 8);
+        assert_eq!(schnorr_sign(Scalar::zero(&secp256k1), b"", &[0u8; 32], &secp256k1), Err(Errors::ZeroPrivateKey));
+        Ok(())
+    }
+}
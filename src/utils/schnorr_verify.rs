@@ -0,0 +1,56 @@
+use crate::types::{Scalar, Point, Points, ECpoint, SchnorrSignature, XOnlyPublicKey, SignatureVerification, EllipticCurve, Errors};
+use crate::utils::tagged_hash;
+use crate::ec_math::multi_scalar_mult;
+
+/// Verifies a BIP340 Schnorr `signature` of `message` under the x only `public_key`:
+/// R = s * G - e * P must be a finite point with even y and x equal to r.
+pub fn schnorr_verify(public_key: &XOnlyPublicKey, message: &[u8], signature: &SchnorrSignature, curve: &EllipticCurve) -> Result<SignatureVerification, Errors> {
+    if signature.r >= curve.p || signature.s >= curve.n {
+        return Ok(SignatureVerification::Failed);
+    }
+    let mut r_bytes = [0u8; 32];
+    signature.r.to_big_endian(&mut r_bytes);
+    let e = Scalar::from_bytes_reduced(&tagged_hash("BIP0340/challenge", &[&r_bytes, &public_key.to_bytes(), message]), curve);
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    #[allow(non_snake_case)]
+    let R = multi_scalar_mult(&[(signature.s, G), ((-e).value(), public_key.to_point().into())], curve)?;
+    match R {
+        ECpoint::OnCurve(Point {x, y}) if !y.bit(0) && x == signature.r => Ok(SignatureVerification::Successful),
+        _ => Ok(SignatureVerification::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schnorr_verify() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // all rows of the official BIP340 vectors, invalid public keys count as failed verification
+        let vectors = include_str!("../../test_vectors/bip340.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.splitn(8, ',').collect();
+            let mut key_bytes = [0u8; 32];
+            key_bytes.copy_from_slice(&hex::decode(fields[2]).unwrap());
+            let message = hex::decode(fields[4]).unwrap();
+            let mut signature_bytes = [0u8; 64];
+            signature_bytes.copy_from_slice(&hex::decode(fields[5]).unwrap());
+            let signature = SchnorrSignature::from_bytes(&signature_bytes);
+            let result = match XOnlyPublicKey::from_bytes(&key_bytes, &secp256k1) {
+                Ok(public_key) => schnorr_verify(&public_key, &message, &signature, &secp256k1)?,
+                Err(_) => SignatureVerification::Failed
+            };
+            let expected = match fields[6] {
+                "TRUE" => SignatureVerification::Successful,
+                _ => SignatureVerification::Failed
+            };
+            assert_eq!(result, expected, "vector {} ({})", fields[0], fields[7]);
+            count += 1;
+        }
+        assert_eq!(count, 19);
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::types::{Scalar, EllipticCurve, Errors, MessageDigest};
+use crate::utils::{bitcoin_message, sign_message_recoverable};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Signs `message` the way Bitcoin Core's signmessage does and returns the Base64 encoded
+/// compact signature. `compressed` tells which address form of the key the signature is for.
+pub fn sign_bitcoin_message(private_key: Scalar, message: &[u8], compressed: bool, curve: &EllipticCurve) -> Result<String, Errors> {
+    let signature = sign_message_recoverable(private_key, &bitcoin_message(message), MessageDigest::DoubleSha256, curve)?;
+    Ok(STANDARD.encode(signature.to_compact(compressed)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+
+    #[test]
+    fn test_sign_bitcoin_message() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // WIF L4rK1yDtCWekvXuE6oXD9jCYfFNV2cWRpVuPLBcCU2z8TrisoyY1, the example of the bitcoinjs-message README
+        let private_key = Scalar::new(U256::from("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"), &secp256k1);
+        let message = b"This is an example of a signed message.";
+        assert_eq!(sign_bitcoin_message(private_key, message, true, &secp256k1)?, "H9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk=");
+        Ok(())
+    }
+}
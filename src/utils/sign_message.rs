@@ -1,70 +1,45 @@
-use crate::types::{U512, U256, Signature, EllipticCurve, ECpoint, Points, Point, Errors};
-use crate::utils::hash_message;
-use crate::ec_math::scalar_mult;
-use crate::group_math::m_inverse_mod;
-use crate::traits::GenRandU256;
+use crate::types::{Scalar, Signature, EllipticCurve, Errors, MessageDigest};
+use crate::utils::sign_message_with_entropy;
 
-/// Creates the hash of the `message` and signs it with `private_key`.
-pub fn sign_message<R: GenRandU256>(rng: &mut R, private_key: U256, message: &[u8], curve: &EllipticCurve) -> Result<Signature, Errors> {
-    let z: U512 = hash_message(message, curve.n.bits()).into();
-    let private_key: U512 = private_key.into();
-    let n: U512 = curve.n.into();
-    #[allow(non_snake_case)]
-    let G = Point::from(curve.g);
-    let mut k;
-    let (mut r, mut s) = (U512::zero(), U512::zero());
-    while r == U512::zero() || s == U512::zero() {
-        k = rng.gen_u256_range(&U256::one(), &curve.n);
-        match scalar_mult(k, &Points::FinitePoint(G), curve)? {
-            ECpoint::Infinity => continue,
-            ECpoint::OnCurve(p) => {
-                let Point {x, ..} = p;
-                let x: U512 = x.into();
-                r = x % n;
-                //s = k_inverse * (z + r*private_key) (mod n)
-                let k_inverse = U512::from(m_inverse_mod(k, curve.n)?);
-                let r_times_private_key = (r *private_key) % n;
-                let z_plus_r_times_private_key = (z + r_times_private_key) % n;
-                s = (k_inverse * z_plus_r_times_private_key)  % n;
-            }
-        }
-    }
-    Ok(Signature::new(r.into(), s.into()))
+/// Hashes the `message` with `digest` and signs it with `private_key`.
+/// The nonce is derived from the private key and the hash (RFC 6979), so signing the same
+/// message twice gives the same signature. s is always low (at most n/2).
+pub fn sign_message(private_key: Scalar, message: &[u8], digest: MessageDigest, curve: &EllipticCurve) -> Result<Signature, Errors> {
+    sign_message_with_entropy(private_key, message, digest, None, curve)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    struct MockRng {
-        ret: U256
-    }
-
-    impl MockRng {
-        fn new(ret: U256) -> Self {
-            MockRng { ret }
-        }
-    }
-
-    impl GenRandU256 for MockRng {
-        fn gen_u256_range(&mut self, _: &U256, _: &U256) -> U256 {self.ret}
-        fn gen_u256(&mut self) -> U256 {self.ret}
-    }
+    use crate::types::{U256, SignatureVerification};
+    use crate::utils::{make_keypair, verify_signature};
 
     #[test]
     fn test_sign_message() -> Result <(), Errors>{
         let secp256k1 = EllipticCurve::secp256k1_factory();
-        let mut rng = MockRng::new(U256::one());
-        let r = U256::from_dec_str("55066263022277343669578718895168534326250603453777594175500187360389116729240").unwrap();
-        let s = U256::from_dec_str("46992992609640467017876430891725436310416382799367731925940059381452197848073").unwrap();
-        assert_eq!(sign_message(&mut rng, U256::from(1), b"test", &secp256k1)?, Signature::from((r, s)));
-        let mut rng = MockRng::new(r);
-        let r = U256::from_dec_str("41368939038460017089690463593392860417892426308765457203329747030588589193225").unwrap();
-        let s = U256::from_dec_str("31053304894587659915590992603088785556232634551332211685482548454257155309727").unwrap();
-        assert_eq!(sign_message(&mut rng, U256::from(1), b"test", &secp256k1)?, Signature::from((r, s)));
-        let mut rng = MockRng::new(s);
-        let r = U256::from_dec_str("77932200203109347009278505663038934845025051719260868904944445924183195568966").unwrap();
-        let s = U256::from_dec_str("18814421266250684974379844796716226997450433558338117269037970871169669132622").unwrap();
-        assert_eq!(sign_message(&mut rng, U256::from(1), b"test", &secp256k1)?, Signature::from((r, s)));
+        #[allow(non_snake_case)]
+        let (a, A) = make_keypair(&secp256k1)?;
+        let sig = sign_message(a, b"test", MessageDigest::Sha256, &secp256k1)?;
+        assert_eq!(sign_message(a, b"test", MessageDigest::Sha256, &secp256k1)?, sig);
+        assert_ne!(sign_message(a, b"test2", MessageDigest::Sha256, &secp256k1)?, sig);
+        assert!(sig.is_low_s(&secp256k1));
+        assert_eq!(verify_signature(A, b"test", &sig, MessageDigest::Sha256, &secp256k1)?, SignatureVerification::Successful);
+        // private key 1, checked against an independent implementation
+        let r = U256::from("ac4c6da0cabc720c71225332740c3ac9007e8722518652f055b526c761936fcd");
+        let s = U256::from("57014704703169929d6fd22ac79784b57fe3a941e4d34d2c95033c7990705c08");
+        assert_eq!(sign_message(Scalar::one(&secp256k1), b"test", MessageDigest::Sha512, &secp256k1)?, Signature::from_u256(r, s, &secp256k1)?);
+        // widely used secp256k1 RFC 6979 vectors, private key 1 with SHA-256 and double SHA-256
+        let message = b"Satoshi Nakamoto";
+        let r = U256::from("934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8");
+        let s = U256::from("2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5");
+        assert_eq!(sign_message(Scalar::one(&secp256k1), message, MessageDigest::Sha256, &secp256k1)?, Signature::from_u256(r, s, &secp256k1)?);
+        let r = U256::from("1db4e63a273c868a1fdc5078e69f7c48e8ec1bec7ed96a3d410650454d67849d");
+        let s = U256::from("7a1a63d3a5163fb95a641e56f0d415d68047f048463f283c7340febc471825f3");
+        assert_eq!(sign_message(Scalar::one(&secp256k1), message, MessageDigest::DoubleSha256, &secp256k1)?, Signature::from_u256(r, s, &secp256k1)?);
+        // a prehashed message is the sighash itself
+        let sighash = MessageDigest::DoubleSha256.hash(message)?;
+        assert_eq!(sign_message(Scalar::one(&secp256k1), &sighash, MessageDigest::Prehashed, &secp256k1)?, Signature::from_u256(r, s, &secp256k1)?);
+        assert_eq!(sign_message(Scalar::one(&secp256k1), message, MessageDigest::Prehashed, &secp256k1), Err(Errors::InvalidPrehashLength(16)));
         Ok(())
     }
 }
@@ -0,0 +1,29 @@
+use crate::types::{Scalar, RecoverableSignature, EllipticCurve, Errors, Rfc6979, MessageDigest};
+use crate::utils::hash_message;
+use crate::utils::sign_message_with_rng::sign_recoverable_with_rng;
+
+/// Same as sign_message, also returning the recovery id so that recover_public_key
+/// can rebuild the public key from the message and the signature.
+pub fn sign_message_recoverable(private_key: Scalar, message: &[u8], digest: MessageDigest, curve: &EllipticCurve) -> Result<RecoverableSignature, Errors> {
+    let z = hash_message(message, digest, curve.n.bits())?;
+    let mut nonces = Rfc6979::new(&private_key, z, None, curve);
+    sign_recoverable_with_rng(&mut nonces, private_key, message, digest, curve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::sign_message;
+
+    #[test]
+    fn test_sign_message_recoverable() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let private_key = Scalar::one(&secp256k1);
+        let message = b"Satoshi Nakamoto";
+        let sig = sign_message_recoverable(private_key, message, MessageDigest::Sha256, &secp256k1)?;
+        // same signature as sign_message, R = k * G has an even y but s was negated, so -R (odd y) is used
+        assert_eq!(sig.signature, sign_message(private_key, message, MessageDigest::Sha256, &secp256k1)?);
+        assert_eq!(sig.recovery_id, 1);
+        Ok(())
+    }
+}
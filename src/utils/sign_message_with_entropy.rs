@@ -0,0 +1,31 @@
+use crate::types::{U256, Scalar, Signature, EllipticCurve, Errors, Rfc6979, MessageDigest};
+use crate::utils::{hash_message, sign_message_with_rng};
+
+/// Same as sign_message, but mixes `extra_entropy` into the nonce derivation (RFC 6979 section 3.6).
+/// Signatures stay deterministic for the same entropy, and are still safe if the entropy is weak.
+pub fn sign_message_with_entropy(private_key: Scalar, message: &[u8], digest: MessageDigest, extra_entropy: Option<&[u8]>, curve: &EllipticCurve) -> Result<Signature, Errors> {
+    let z: U256 = hash_message(message, digest, curve.n.bits())?;
+    let mut nonces = Rfc6979::new(&private_key, z, extra_entropy, curve);
+    sign_message_with_rng(&mut nonces, private_key, message, digest, curve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SignatureVerification;
+    use crate::utils::{make_keypair, sign_message, verify_signature};
+
+    #[test]
+    fn test_sign_message_with_entropy() -> Result <(), Errors>{
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let (a, A) = make_keypair(&secp256k1)?;
+        let sig = sign_message_with_entropy(a, b"test", MessageDigest::Sha256, Some(b"entropy"), &secp256k1)?;
+        assert_eq!(sign_message_with_entropy(a, b"test", MessageDigest::Sha256, Some(b"entropy"), &secp256k1)?, sig);
+        assert_ne!(sign_message_with_entropy(a, b"test", MessageDigest::Sha256, Some(b"other entropy"), &secp256k1)?, sig);
+        assert_ne!(sign_message(a, b"test", MessageDigest::Sha256, &secp256k1)?, sig);
+        assert_eq!(sign_message_with_entropy(a, b"test", MessageDigest::Sha256, None, &secp256k1)?, sign_message(a, b"test", MessageDigest::Sha256, &secp256k1)?);
+        assert_eq!(verify_signature(A, b"test", &sig, MessageDigest::Sha256, &secp256k1)?, SignatureVerification::Successful);
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::types::{U256, Scalar, Signature, RecoverableSignature, EllipticCurve, ECpoint, Point, Errors, MessageDigest};
+use crate::utils::hash_message;
+use crate::ec_math::scalar_mult_base;
+use crate::traits::GenRandU256;
+
+/// Hashes the `message` with `digest` and signs it with `private_key`, drawing the nonce k from `rng`.
+/// A weak rng leaks the private key, sign_message derives k deterministically instead. s is always low (at most n/2).
+pub fn sign_message_with_rng<R: GenRandU256>(rng: &mut R, private_key: Scalar, message: &[u8], digest: MessageDigest, curve: &EllipticCurve) -> Result<Signature, Errors> {
+    Ok(sign_recoverable_with_rng(rng, private_key, message, digest, curve)?.signature)
+}
+
+/// Same as sign_message_with_rng, also returning the recovery id of the nonce point R.
+pub(crate) fn sign_recoverable_with_rng<R: GenRandU256>(rng: &mut R, private_key: Scalar, message: &[u8], digest: MessageDigest, curve: &EllipticCurve) -> Result<RecoverableSignature, Errors> {
+    let z = Scalar::new(hash_message(message, digest, curve.n.bits())?, curve);
+    let (mut r, mut s) = (Scalar::zero(curve), Scalar::zero(curve));
+    let mut recovery_id = 0;
+    while r.is_zero() || s.is_zero() {
+        let k = Scalar::new(rng.gen_u256_range(&U256::one(), &curve.n), curve);
+        match scalar_mult_base(k.value(), curve)? {
+            ECpoint::Infinity => continue,
+            ECpoint::OnCurve(p) => {
+                let Point {x, y} = p;
+                r = Scalar::new(x, curve);
+                //s = k_inverse * (z + r*private_key) (mod n)
+                s = k.inverse()? * (z + r * private_key);
+                recovery_id = RecoverableSignature::recovery_id(x, y, curve);
+            }
+        }
+    }
+    let signature = Signature::new(r, s);
+    // n - s belongs to the nonce -k, whose point -R has the other parity
+    if !signature.is_low_s(curve) {
+        recovery_id ^= 1;
+    }
+    Ok(RecoverableSignature::new(signature.normalize_s(curve), recovery_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    struct MockRng {
+        ret: U256
+    }
+
+    impl MockRng {
+        fn new(ret: U256) -> Self {
+            MockRng { ret }
+        }
+    }
+
+    impl GenRandU256 for MockRng {
+        fn gen_u256_range(&mut self, _: &U256, _: &U256) -> U256 {self.ret}
+        fn gen_u256(&mut self) -> U256 {self.ret}
+    }
+
+    #[test]
+    fn test_sign_message_with_rng() -> Result <(), Errors>{
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let mut rng = MockRng::new(U256::one());
+        let r = U256::from_dec_str("55066263022277343669578718895168534326250603453777594175500187360389116729240").unwrap();
+        let s = U256::from_dec_str("46992992609640467017876430891725436310416382799367731925940059381452197848073").unwrap();
+        assert_eq!(sign_message_with_rng(&mut rng, Scalar::one(&secp256k1), b"test", MessageDigest::Sha512, &secp256k1)?, Signature::from_u256(r, s, &secp256k1)?);
+        let mut rng = MockRng::new(r);
+        let r = U256::from_dec_str("41368939038460017089690463593392860417892426308765457203329747030588589193225").unwrap();
+        let s = U256::from_dec_str("31053304894587659915590992603088785556232634551332211685482548454257155309727").unwrap();
+        assert_eq!(sign_message_with_rng(&mut rng, Scalar::one(&secp256k1), b"test", MessageDigest::Sha512, &secp256k1)?, Signature::from_u256(r, s, &secp256k1)?);
+        let mut rng = MockRng::new(s);
+        let r = U256::from_dec_str("77932200203109347009278505663038934845025051719260868904944445924183195568966").unwrap();
+        let s = U256::from_dec_str("18814421266250684974379844796716226997450433558338117269037970871169669132622").unwrap();
+        assert_eq!(sign_message_with_rng(&mut rng, Scalar::one(&secp256k1), b"test", MessageDigest::Sha512, &secp256k1)?, Signature::from_u256(r, s, &secp256k1)?);
+        // k = 3 gives a high s, it is replaced by n - s
+        let mut rng = MockRng::new(U256::from(3));
+        let sig = sign_message_with_rng(&mut rng, Scalar::one(&secp256k1), b"test", MessageDigest::Sha512, &secp256k1)?;
+        assert!(sig.is_low_s(&secp256k1));
+        Ok(())
+    }
+}
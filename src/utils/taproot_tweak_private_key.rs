@@ -0,0 +1,73 @@
+use crate::types::{Scalar, XOnlyPublicKey, EllipticCurve, Errors};
+use crate::ec_math::{scalar_mult_base, tap_tweak};
+
+/// Tweaks the internal private key d the same way `taproot_tweak_public_key` tweaks its public key, the result
+/// signs for the output key on the key path. d is negated first if d * G has an odd y, then d + t is returned.
+pub fn taproot_tweak_private_key(private_key: Scalar, merkle_root: Option<&[u8; 32]>, curve: &EllipticCurve) -> Result<Scalar, Errors> {
+    if private_key.is_zero() {
+        return Err(Errors::ZeroPrivateKey);
+    }
+    let point = scalar_mult_base(private_key.value(), curve)?;
+    let d = match point.to_finite_point().y.bit(0) {
+        true => -private_key,
+        false => private_key
+    };
+    let t = tap_tweak(&XOnlyPublicKey::from_point(&point, curve)?, merkle_root, curve)?;
+    let tweaked = d + t;
+    // d + t = 0 means the output key is the point at infinity
+    match tweaked.is_zero() {
+        true => Err(Errors::PointAtInfinity),
+        false => Ok(tweaked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+    use crate::utils::schnorr_sign;
+    use crate::ec_math::taproot_tweak_public_key;
+
+    #[test]
+    fn test_taproot_tweak_private_key() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        // keyPathSpending inputs of the BIP341 wallet test vectors
+        let vectors = include_str!("../../test_vectors/bip341_key_path.csv");
+        let mut count = 0;
+        for line in vectors.lines().skip(1) {
+            let fields: Vec<&str> = line.split(',').collect();
+            let private_key = Scalar::new(fields[1].parse::<U256>().unwrap(), &secp256k1);
+            let mut root = [0u8; 32];
+            let merkle_root = match fields[2].is_empty() {
+                true => None,
+                false => {
+                    root.copy_from_slice(&hex::decode(fields[2]).unwrap());
+                    Some(&root)
+                }
+            };
+            let internal_key = XOnlyPublicKey::from_point(&scalar_mult_base(private_key.value(), &secp256k1)?, &secp256k1)?;
+            assert_eq!(hex::encode(internal_key.to_bytes()), fields[4], "internal key of input {}", fields[0]);
+            let tweaked = taproot_tweak_private_key(private_key, merkle_root, &secp256k1)?;
+            assert_eq!(hex::encode(tweaked.to_bytes()), fields[6], "tweaked key of input {}", fields[0]);
+            // the tweaked private key belongs to the tweaked public key, parity included
+            let (output_key, parity) = taproot_tweak_public_key(&internal_key, merkle_root, &secp256k1)?;
+            let point = scalar_mult_base(tweaked.value(), &secp256k1)?.to_finite_point();
+            assert_eq!((point.x, point.y.bit(0)), (output_key.x(), parity));
+            // the key path witness is a BIP340 signature of the sighash with zero aux_rand,
+            // followed by the hash type byte unless it is SIGHASH_DEFAULT
+            let mut sighash = [0u8; 32];
+            sighash.copy_from_slice(&hex::decode(fields[7]).unwrap());
+            let signature = schnorr_sign(tweaked, &sighash, &[0u8; 32], &secp256k1)?;
+            let hash_type: u8 = fields[3].parse().unwrap();
+            let mut witness = signature.to_bytes().to_vec();
+            if hash_type != 0 {
+                witness.push(hash_type);
+            }
+            assert_eq!(hex::encode(witness), fields[8], "witness of input {}", fields[0]);
+            count += 1;
+        }
+        assert_eq!(count, 7);
+        assert_eq!(taproot_tweak_private_key(Scalar::zero(&secp256k1), None, &secp256k1), Err(Errors::ZeroPrivateKey));
+        Ok(())
+    }
+}
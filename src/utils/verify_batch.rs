@@ -0,0 +1,95 @@
+use crate::types::{U256, FieldElement, Scalar, Signature, SignatureVerification, EllipticCurve, Errors, Points, Point, ECpoint, MessageDigest};
+use crate::utils::hash_message;
+use crate::ec_math::multi_scalar_mult::multi_scalar_mult_jacobian;
+use crate::group_math::batch_inverse;
+
+/// Verifies many (public_key, message, signature) entries and returns the result of each of them.
+/// All s values are inverted with a single shared inversion, u1 * G + u2 * public_key is computed
+/// with shared doublings, and the x coordinate is compared in Jacobian coordinates, so no
+/// further inversions are needed. Gives the same answers as verify_signature for every entry,
+/// so a zero r or s fails and high-S is accepted.
+pub fn verify_batch(entries: &[(ECpoint, &[u8], Signature)], digest: MessageDigest, curve: &EllipticCurve) -> Result<Vec<SignatureVerification>, Errors> {
+    let mut s_inverses: Vec<U256> = entries.iter().map(|(_, _, signature)| signature.s.value()).collect();
+    // a zero s stays zero, such entries fail below
+    batch_inverse(&mut s_inverses, curve.n)?;
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    let mut results = Vec::with_capacity(entries.len());
+    for ((public_key, message, signature), s_inv) in entries.iter().zip(s_inverses) {
+        let s_inv = Scalar::new(s_inv, curve);
+        let r = signature.r;
+        let public_key = match public_key {
+            ECpoint::OnCurve(public_key) if !r.is_zero() && !signature.s.is_zero() => public_key,
+            _ => {
+                results.push(SignatureVerification::Failed);
+                continue;
+            }
+        };
+        let z = Scalar::new(hash_message(message, digest, curve.n.bits())?, curve);
+        let u1 = s_inv * z;
+        let u2 = s_inv * r;
+        let res = match multi_scalar_mult_jacobian(&[(u1.value(), G), (u2.value(), Points::FinitePoint(*public_key))], curve) {
+            Ok(res) if !res.is_infinity() => res,
+            _ => {
+                results.push(SignatureVerification::Failed);
+                continue;
+            }
+        };
+        // x = X / Z^2, so x = r (mod n) means X = r * Z^2 or X = (r + n) * Z^2 (if r + n < p)
+        let z_2 = res.z.square();
+        let r_times_z_2 = FieldElement::new(r.value(), curve.p) * z_2;
+        let mut matches = res.x == r_times_z_2;
+        let (r_plus_n, overflow) = r.value().overflowing_add(curve.n);
+        if !overflow && r_plus_n < curve.p {
+            matches = matches || res.x == FieldElement::new(r_plus_n, curve.p) * z_2;
+        }
+        results.push(match matches {
+            true => SignatureVerification::Successful,
+            false => SignatureVerification::Failed
+        });
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{sign_message, make_keypair, verify_signature};
+
+    #[test]
+    fn test_verify_batch() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let (a, A) = make_keypair(&secp256k1)?;
+        #[allow(non_snake_case)]
+        let (b, B) = make_keypair(&secp256k1)?;
+        let msg: &[u8] = b"This is a test";
+        let other_msg: &[u8] = b"This should fail";
+        let sig_a = sign_message(a, msg, MessageDigest::Sha256, &secp256k1)?;
+        let sig_b = sign_message(b, other_msg, MessageDigest::Sha256, &secp256k1)?;
+        let entries = vec![
+            (A, msg, Signature::new(sig_a.r, sig_a.s)),
+            (B, other_msg, Signature::new(sig_b.r, sig_b.s)),
+            // wrong message
+            (A, other_msg, Signature::new(sig_a.r, sig_a.s)),
+            // wrong key
+            (B, msg, Signature::new(sig_a.r, sig_a.s)),
+            // zero s and zero r
+            (A, msg, Signature::new(sig_a.r, Scalar::zero(&secp256k1))),
+            (A, msg, Signature::new(Scalar::zero(&secp256k1), sig_a.s)),
+            // s off by one
+            (A, msg, Signature::new(sig_a.r, sig_a.s + Scalar::one(&secp256k1))),
+            // infinity as public key
+            (ECpoint::Infinity, msg, Signature::new(sig_a.r, sig_a.s)),
+        ];
+        let results = verify_batch(&entries, MessageDigest::Sha256, &secp256k1)?;
+        use SignatureVerification::*;
+        assert_eq!(results, vec![Successful, Successful, Failed, Failed, Failed, Failed, Failed, Failed]);
+        // same answers as the single signature path
+        for (entry, result) in entries.iter().zip(results) {
+            assert_eq!(verify_signature(entry.0, entry.1, &entry.2, MessageDigest::Sha256, &secp256k1)?, result);
+        }
+        assert_eq!(verify_batch(&[], MessageDigest::Sha256, &secp256k1)?, vec![]);
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::types::{EllipticCurve, Errors, MessageDigest, RecoverableSignature, SignatureVerification};
+use crate::utils::{bitcoin_message, recover_public_key, p2pkh_address};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Checks a Base64 signature of Bitcoin Core's signmessage against the P2PKH `address`, like verifymessage.
+/// Malformed signatures are errors, a signature that recovers no key or the key of an other address fails.
+pub fn verify_bitcoin_message(address: &str, message: &[u8], signature: &str, curve: &EllipticCurve) -> Result<SignatureVerification, Errors> {
+    let bytes = STANDARD.decode(signature).map_err(|_| Errors::InvalidBase64)?;
+    let (signature, compressed) = RecoverableSignature::from_compact(&bytes, curve)?;
+    let public_key = match recover_public_key(&bitcoin_message(message), &signature, MessageDigest::DoubleSha256, curve) {
+        Ok(public_key) => public_key,
+        Err(_) => return Ok(SignatureVerification::Failed)
+    };
+    match p2pkh_address(&public_key, compressed, curve) == address {
+        true => Ok(SignatureVerification::Successful),
+        false => Ok(SignatureVerification::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{U256, Scalar};
+    use crate::ec_math::scalar_mult_base;
+    use crate::utils::sign_bitcoin_message;
+
+    #[test]
+    fn test_verify_bitcoin_message() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        let address = "1F3sAm6ZtwLAUnj7d38pGFxtP3RVEvtsbV";
+        let message = b"This is an example of a signed message.";
+        let signature = "H9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk=";
+        use SignatureVerification::*;
+        assert_eq!(verify_bitcoin_message(address, message, signature, &secp256k1)?, Successful);
+        assert_eq!(verify_bitcoin_message(address, b"This is an other message.", signature, &secp256k1)?, Failed);
+        assert_eq!(verify_bitcoin_message("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH", message, signature, &secp256k1)?, Failed);
+        // produced by Bitcoin Core, from the message_verify test of its src/test/util_tests.cpp
+        let signature = "IPojfrX2dfPnH26UegfbGQQLrdK844DlHq5157/P6h57WyuS/Qsl+h/WSVGDF4MUi4rWSswW38oimDYfNNUBUOk=";
+        assert_eq!(verify_bitcoin_message("15CRxFdyRpGZLW9w8HnHvVduizdL5jKNbs", b"Trust no one", signature, &secp256k1)?, Successful);
+        assert_eq!(verify_bitcoin_message("1KqbBpLy5FARmTPD4VZnDDpYjkUvkr82Pm", b"Trust no one", signature, &secp256k1)?, Failed);
+        assert_eq!(verify_bitcoin_message(address, message, "not base64!", &secp256k1), Err(Errors::InvalidBase64));
+        assert_eq!(verify_bitcoin_message(address, message, "AAAA", &secp256k1), Err(Errors::InvalidCompactLength(3)));
+        // the uncompressed key has an other address
+        let private_key = Scalar::new(U256::from("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"), &secp256k1);
+        let uncompressed = p2pkh_address(&scalar_mult_base(private_key.value(), &secp256k1)?, false, &secp256k1);
+        let signature = sign_bitcoin_message(private_key, message, false, &secp256k1)?;
+        assert_eq!(verify_bitcoin_message(&uncompressed, message, &signature, &secp256k1)?, Successful);
+        assert_eq!(verify_bitcoin_message(address, message, &signature, &secp256k1)?, Failed);
+        Ok(())
+    }
+}
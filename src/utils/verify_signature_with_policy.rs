@@ -0,0 +1,61 @@
+use crate::types::{Scalar, Signature, SignatureVerification, VerificationPolicy, EllipticCurve, Errors, Points, Point, ECpoint, MessageDigest};
+use crate::utils::hash_message;
+use crate::ec_math::multi_scalar_mult;
+
+/// Same as verify_signature, with `policy` deciding if a high-S signature is accepted.
+/// Signatures with a zero r or s and the point at infinity as public key always fail.
+pub fn verify_signature_with_policy(public_key: ECpoint, message: &[u8], signature: &Signature, digest: MessageDigest, policy: VerificationPolicy, curve: &EllipticCurve) -> Result<SignatureVerification, Errors> {
+    if signature.r.is_zero() || signature.s.is_zero() {
+        return Ok(SignatureVerification::Failed);
+    }
+    if policy == VerificationPolicy::Strict && !signature.is_low_s(curve) {
+        return Ok(SignatureVerification::Failed);
+    }
+    let public_key = match public_key {
+        ECpoint::OnCurve(public_key) => public_key,
+        ECpoint::Infinity => return Ok(SignatureVerification::Failed)
+    };
+    let z = Scalar::new(hash_message(message, digest, curve.n.bits())?, curve);
+    #[allow(non_snake_case)]
+    let G = Points::FinitePoint(Point::from(curve.g));
+    let r = signature.r;
+    let s_inv = signature.s.inverse_vartime()?;
+    let u1 = s_inv * z;
+    let u2 = s_inv * r;
+    // u1 * G + u2 * public_key, sharing the doublings
+    let res = multi_scalar_mult(&[(u1.value(), G), (u2.value(), Points::FinitePoint(public_key))], curve)?;
+
+    match res {
+        ECpoint::OnCurve(Point{x, y: _}) if r == Scalar::new(x, curve) => Ok(SignatureVerification::Successful),
+        _ => Ok(SignatureVerification::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{sign_message, make_keypair};
+
+    #[test]
+    fn test_verify_signature_with_policy() -> Result<(), Errors> {
+        let secp256k1 = EllipticCurve::secp256k1_factory();
+        #[allow(non_snake_case)]
+        let (a, A) = make_keypair(&secp256k1)?;
+        let msg = b"This is a test";
+        let low = sign_message(a, msg, MessageDigest::Sha256, &secp256k1)?;
+        let high = Signature::new(low.r, -low.s);
+        use VerificationPolicy::*;
+        use SignatureVerification::*;
+        assert_eq!(verify_signature_with_policy(A, msg, &low, MessageDigest::Sha256, Lax, &secp256k1)?, Successful);
+        assert_eq!(verify_signature_with_policy(A, msg, &low, MessageDigest::Sha256, Strict, &secp256k1)?, Successful);
+        assert_eq!(verify_signature_with_policy(A, msg, &high, MessageDigest::Sha256, Lax, &secp256k1)?, Successful);
+        assert_eq!(verify_signature_with_policy(A, msg, &high, MessageDigest::Sha256, Strict, &secp256k1)?, Failed);
+        // r and s must not be zero, values of n and above are already rejected by Signature::from_u256
+        let zero = Scalar::zero(&secp256k1);
+        for sig in [Signature::new(zero, low.s), Signature::new(low.r, zero)].iter() {
+            assert_eq!(verify_signature_with_policy(A, msg, sig, MessageDigest::Sha256, Lax, &secp256k1)?, Failed);
+        }
+        assert_eq!(verify_signature_with_policy(ECpoint::Infinity, msg, &low, MessageDigest::Sha256, Lax, &secp256k1)?, Failed);
+        Ok(())
+    }
+}